@@ -1,6 +1,222 @@
 use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::validation::validate_authority;
+use crate::instructions::{
+    errors::ErrorCode,
+    events::{AdminProposalExecuted, ProponentProposalExecuted},
+};
 
-pub fn has_permission(user: &Signer, role: &str) -> bool {
-    msg!("Checking access for user {:?} with role {}", user.key(), role);
-    true
+/// Returns true if `user` may act with `role`.
+///
+/// In single-key mode the registry admin implicitly holds every role.
+/// Everyone else, and the admin key too while multisig mode is enabled,
+/// needs a `RoleAssignment` issued to them for exactly that role.
+pub fn has_permission(
+    user: &Signer,
+    role: Role,
+    registry: &GlobalRegistry,
+    assignment: Option<&RoleAssignment>,
+) -> bool {
+    if !registry.multisig_enabled && user.key() == registry.admin {
+        return true;
+    }
+
+    let granted = match assignment {
+        Some(assignment) => assignment.holder == user.key() && assignment.role == role,
+        None => false,
+    };
+
+    if !granted {
+        msg!("Access denied for user {:?} with role {:?}", user.key(), role);
+    }
+    granted
+}
+
+/// Consumes an approved multisig proposal authorizing `action`.
+///
+/// Only meaningful while `registry.multisig_enabled`; callers fall back to
+/// their single-key checks otherwise. The executor must be one of the
+/// approvers, and the proposal can only be executed once.
+pub fn execute_admin_proposal(
+    executor: &Signer,
+    registry: &GlobalRegistry,
+    proposal: Option<&mut Account<AdminProposal>>,
+    action: &AdminAction,
+) -> Result<()> {
+    let proposal = proposal.ok_or(ErrorCode::MultisigApprovalRequired)?;
+    check_admin_proposal(proposal, registry, &executor.key(), action)?;
+
+    proposal.executed = true;
+
+    emit!(AdminProposalExecuted {
+        proposal: proposal.key(),
+        executor: executor.key(),
+    });
+
+    Ok(())
+}
+
+/// Whether `executor` may execute `proposal` for `action` now: still open,
+/// collected under the current key set, approved by at least the threshold,
+/// for exactly this action, and approved by the executor too.
+fn check_admin_proposal(
+    proposal: &AdminProposal,
+    registry: &GlobalRegistry,
+    executor: &Pubkey,
+    action: &AdminAction,
+) -> Result<()> {
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(
+        proposal.multisig_nonce == registry.multisig_nonce,
+        ErrorCode::StaleProposal
+    );
+    require!(
+        proposal.approvals.len() >= proposal.threshold as usize,
+        ErrorCode::InsufficientApprovals
+    );
+    require!(proposal.action == *action, ErrorCode::ProposalActionMismatch);
+    require!(
+        proposal.approvals.contains(executor),
+        ErrorCode::NotMultisigMember
+    );
+    Ok(())
+}
+
+/// Admin key in single mode, an approved proposal for `action` in multisig
+/// mode.
+pub fn authorize_admin_action(
+    authority: &Signer,
+    registry: &GlobalRegistry,
+    proposal: Option<&mut Account<AdminProposal>>,
+    action: AdminAction,
+) -> Result<()> {
+    if registry.multisig_enabled {
+        execute_admin_proposal(authority, registry, proposal, &action)
+    } else {
+        validate_authority(authority, registry)
+    }
+}
+
+/// Authorizes a sensitive project action. Projects without co-proponents
+/// need nothing further; otherwise this consumes an approved
+/// ProponentProposal for exactly `action`.
+pub fn execute_proponent_proposal(
+    executor: &Signer,
+    project: &Account<Project>,
+    proposal: Option<&mut Account<ProponentProposal>>,
+    action: &ProponentAction,
+) -> Result<()> {
+    if project.proponents.is_empty() {
+        return Ok(());
+    }
+    let proposal = proposal.ok_or(ErrorCode::ProponentApprovalRequired)?;
+
+    require_keys_eq!(proposal.project, project.key(), ErrorCode::ProposalProjectMismatch);
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(
+        proposal.proponent_nonce == project.proponent_nonce,
+        ErrorCode::StaleProposal
+    );
+    require!(
+        proposal.approvals.len() >= project.proponent_threshold as usize,
+        ErrorCode::InsufficientApprovals
+    );
+    require!(proposal.action == *action, ErrorCode::ProposalActionMismatch);
+
+    proposal.executed = true;
+
+    emit!(ProponentProposalExecuted {
+        proposal: proposal.key(),
+        project: project.key(),
+        executor: executor.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    fn registry() -> GlobalRegistry {
+        GlobalRegistry {
+            multisig_enabled: true,
+            multisig_nonce: 3,
+            ..Default::default()
+        }
+    }
+
+    fn action() -> AdminAction {
+        AdminAction::Unpause { subsystem: Subsystem::Minting }
+    }
+
+    // A 2-of-3 proposal approved by its proposer only
+    fn proposal() -> AdminProposal {
+        AdminProposal {
+            index: 0,
+            multisig_nonce: 3,
+            proposer: key(1),
+            action: action(),
+            approvals: vec![key(1)],
+            threshold: 2,
+            created_at: 0,
+            executed: false,
+            bump: 255,
+        }
+    }
+
+    fn check(proposal: &AdminProposal, executor: u8, action: &AdminAction) -> Result<()> {
+        check_admin_proposal(proposal, &registry(), &key(executor), action)
+    }
+
+    #[test]
+    fn executes_once_the_threshold_is_met() {
+        let mut proposal = proposal();
+        assert_eq!(check(&proposal, 1, &action()), Err(ErrorCode::InsufficientApprovals.into()));
+
+        proposal.approvals.push(key(2));
+        assert_eq!(check(&proposal, 1, &action()), Ok(()));
+        assert_eq!(check(&proposal, 2, &action()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_an_executor_who_did_not_approve() {
+        let mut proposal = proposal();
+        proposal.approvals.push(key(2));
+        // A member who has not approved, or anyone else
+        assert_eq!(check(&proposal, 3, &action()), Err(ErrorCode::NotMultisigMember.into()));
+        assert_eq!(check(&proposal, 9, &action()), Err(ErrorCode::NotMultisigMember.into()));
+    }
+
+    #[test]
+    fn rejects_a_different_action() {
+        let mut proposal = proposal();
+        proposal.approvals.push(key(2));
+        let other = AdminAction::Unpause { subsystem: Subsystem::Marketplace };
+        assert_eq!(check(&proposal, 1, &other), Err(ErrorCode::ProposalActionMismatch.into()));
+        assert_eq!(
+            check(&proposal, 1, &AdminAction::DisableMultisig),
+            Err(ErrorCode::ProposalActionMismatch.into())
+        );
+    }
+
+    #[test]
+    fn rejects_proposals_from_an_earlier_key_set() {
+        let mut proposal = proposal();
+        proposal.approvals.push(key(2));
+        proposal.multisig_nonce = 2;
+        assert_eq!(check(&proposal, 1, &action()), Err(ErrorCode::StaleProposal.into()));
+    }
+
+    #[test]
+    fn rejects_executed_proposals() {
+        let mut proposal = proposal();
+        proposal.approvals.push(key(2));
+        proposal.executed = true;
+        assert_eq!(check(&proposal, 1, &action()), Err(ErrorCode::ProposalAlreadyExecuted.into()));
+    }
 }
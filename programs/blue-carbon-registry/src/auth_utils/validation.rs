@@ -1,6 +1,734 @@
 use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::fixed::*;
+use crate::geo::{on_segment, orientation, ring_within, segments_intersect};
+use crate::instructions::errors::ErrorCode;
 
-pub fn validate_authority(authority: &Signer) -> Result<()> {
-    msg!("Validating authority: {:?}", authority.key());
+/// Ensures `authority` is the registry admin.
+pub fn validate_authority(authority: &Signer, registry: &GlobalRegistry) -> Result<()> {
+    require_keys_eq!(authority.key(), registry.admin, ErrorCode::Unauthorized);
     Ok(())
 }
+
+/// Checks an admin multisig key set: 1..=MAX_MEMBERS unique members and a
+/// threshold that can actually be reached.
+pub fn validate_multisig_config(members: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= AdminMultisig::MAX_MEMBERS,
+        ErrorCode::InvalidMultisigConfig
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= members.len(),
+        ErrorCode::InvalidMultisigConfig
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(!members[i + 1..].contains(member), ErrorCode::InvalidMultisigConfig);
+    }
+    Ok(())
+}
+
+/// Checks a co-proponent list: up to MAX_PROPONENTS unique keys with
+/// non-zero shares totalling 100%, and a reachable approval threshold. An
+/// empty list with a zero threshold hands control back to the owner alone.
+pub fn validate_proponents(proponents: &[Proponent], threshold: u8) -> Result<()> {
+    if proponents.is_empty() {
+        require!(threshold == 0, ErrorCode::InvalidProponents);
+        return Ok(());
+    }
+    require!(proponents.len() <= Project::MAX_PROPONENTS, ErrorCode::InvalidProponents);
+    require!(
+        threshold >= 1 && threshold as usize <= proponents.len(),
+        ErrorCode::InvalidProponents
+    );
+    for (i, proponent) in proponents.iter().enumerate() {
+        require!(proponent.share_bps > 0, ErrorCode::InvalidProponents);
+        require!(
+            !proponents[i + 1..].iter().any(|other| other.key == proponent.key),
+            ErrorCode::InvalidProponents
+        );
+    }
+    let total: u32 = proponents.iter().map(|proponent| proponent.share_bps as u32).sum();
+    require!(
+        total == BasisPoints::HUNDRED_PERCENT.0 as u32,
+        ErrorCode::InvalidProponents
+    );
+    Ok(())
+}
+
+/// Fails with the subsystem's dedicated error while it is paused.
+pub fn require_not_paused(registry: &GlobalRegistry, subsystem: Subsystem) -> Result<()> {
+    if !registry.is_paused(subsystem) {
+        return Ok(());
+    }
+    let error = match subsystem {
+        Subsystem::Registration => ErrorCode::RegistrationPaused,
+        Subsystem::Verification => ErrorCode::VerificationPaused,
+        Subsystem::Minting => ErrorCode::MintingPaused,
+        Subsystem::Transfers => ErrorCode::TransfersPaused,
+        Subsystem::Marketplace => ErrorCode::MarketplacePaused,
+    };
+    Err(error.into())
+}
+
+/// Fails unless credits may be minted or listed for a project in `status`,
+/// with a dedicated error for suspended and expired projects.
+pub fn require_issuing(status: &VerificationStatus) -> Result<()> {
+    let error = match status {
+        status if status.is_issuing() => return Ok(()),
+        VerificationStatus::Suspended => ErrorCode::ProjectSuspended,
+        VerificationStatus::Expired => ErrorCode::ProjectExpired,
+        _ => ErrorCode::ProjectNotVerified,
+    };
+    Err(error.into())
+}
+
+/// Credits can only be minted for vintages inside the project's crediting
+/// period that have already started.
+pub fn require_vintage_creditable(project: &Project, vintage_year: u16) -> Result<()> {
+    let current_year = calendar_year(Clock::get()?.unix_timestamp);
+    require!(
+        project.vintage_in_crediting_period(vintage_year) && vintage_year as i64 <= current_year,
+        ErrorCode::VintageOutsideCreditingPeriod
+    );
+    Ok(())
+}
+
+/// Once a project's baseline is locked, the tokens minted for it may not
+/// exceed the net removals its monitoring periods have recorded.
+pub fn require_within_net_removals(project: &Project, tokens_per_ton: u64, amount: u64) -> Result<()> {
+    if !project.baseline_locked() {
+        return Ok(());
+    }
+    let capacity = (project.net_removals.0 / Tonnes::SCALE)
+        .checked_mul(tokens_per_ton)
+        .ok_or(ErrorCode::ExceedsNetRemovals)?;
+    let minted = project
+        .tokens_minted
+        .checked_add(amount)
+        .ok_or(ErrorCode::ExceedsNetRemovals)?;
+    require!(minted <= capacity, ErrorCode::ExceedsNetRemovals);
+    Ok(())
+}
+
+/// Rejects strings longer than a configured limit.
+pub fn validate_len(value: &str, max_len: u16) -> Result<()> {
+    require!(value.len() <= max_len as usize, ErrorCode::InputTooLong);
+    Ok(())
+}
+
+/// Rejects collections with more than `max` entries.
+pub fn validate_max_entries(len: usize, max: usize) -> Result<()> {
+    require!(len <= max, ErrorCode::TooManyEntries);
+    Ok(())
+}
+
+/// Rejects anything that is not an IPFS content identifier within the
+/// configured length: CIDv0 (`Qm...`, base58btc) or CIDv1 in base32 (`b`/`B`)
+/// or base58btc (`z`) multibase, with a known codec and multihash.
+pub fn validate_cid(cid: &str, max_len: u16) -> Result<()> {
+    validate_len(cid, max_len)?;
+    require!(parse_cid(cid).is_some(), ErrorCode::InvalidContentId);
+    Ok(())
+}
+
+const CIDV0_LEN: usize = 46;
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE32_UPPER: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// Multicodec content types we accept: raw, dag-pb, dag-cbor, dag-json
+const CONTENT_CODECS: [u64; 4] = [0x55, 0x70, 0x71, 0x0129];
+
+// Multihash functions we accept, with their digest length:
+// sha2-256, blake3, blake2b-256
+const HASH_FUNCTIONS: [(u64, u64); 3] = [(0x12, 32), (0x1e, 32), (0xb220, 32)];
+
+fn parse_cid(cid: &str) -> Option<()> {
+    if cid.len() == CIDV0_LEN && cid.starts_with("Qm") {
+        // CIDv0 is a bare sha2-256 multihash
+        let bytes = decode_base58(cid)?;
+        return (bytes.len() == 34 && bytes[0] == 0x12 && bytes[1] == 32).then_some(());
+    }
+
+    let (prefix, encoded) = (cid.get(..1)?, cid.get(1..)?);
+    let bytes = match prefix {
+        "b" => decode_base32(encoded, BASE32_LOWER)?,
+        "B" => decode_base32(encoded, BASE32_UPPER)?,
+        "z" => decode_base58(encoded)?,
+        _ => return None,
+    };
+
+    let mut input = bytes.as_slice();
+    let version = read_varint(&mut input)?;
+    let codec = read_varint(&mut input)?;
+    let hash_function = read_varint(&mut input)?;
+    let digest_len = read_varint(&mut input)?;
+
+    (version == 1
+        && CONTENT_CODECS.contains(&codec)
+        && HASH_FUNCTIONS.contains(&(hash_function, digest_len))
+        && input.len() as u64 == digest_len)
+        .then_some(())
+}
+
+/// Reads one unsigned LEB128 varint, as used by multiformats.
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, &byte) in input.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *input = &input[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_base58(input: &str) -> Option<Vec<u8>> {
+    // Little-endian big number, reversed at the end
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for c in input.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = input.bytes().take_while(|&c| c == b'1').count();
+    bytes.resize(bytes.len() + leading_zeros, 0);
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// RFC 4648 base32 without padding; trailing bits must be zero.
+fn decode_base32(input: &str, alphabet: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.bytes() {
+        buffer = (buffer << 5) | alphabet.iter().position(|&a| a == c)? as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    (bits < 5 && buffer == 0).then_some(bytes)
+}
+
+/// Range checks for project carbon science figures.
+pub fn validate_carbon_science(
+    uncertainty: Option<BasisPoints>,
+    leakage: Option<BasisPoints>,
+) -> Result<()> {
+    for value in [uncertainty, leakage].into_iter().flatten() {
+        require!(value.is_fraction(), ErrorCode::InvalidCarbonMeasurement);
+    }
+    Ok(())
+}
+
+/// Range checks for submitted monitoring readings.
+pub fn validate_monitoring_readings(input: &MonitoringDataInput) -> Result<()> {
+    let ndvi_range = Measure(-Measure::SCALE)..=Measure(Measure::SCALE);
+
+    require!(ndvi_range.contains(&input.ndvi_index), ErrorCode::ValueOutOfRange);
+    require!(PH_RANGE.contains(&input.water_quality.ph_level), ErrorCode::ValueOutOfRange);
+    validate_max_entries(input.temperature_data.len(), MonitoringData::MAX_TEMPERATURE_READINGS)?;
+    validate_max_entries(input.tide_data.len(), MonitoringData::MAX_TIDE_READINGS)?;
+    validate_max_entries(input.iot_sensor_data.len(), MonitoringData::MAX_SENSOR_READINGS)?;
+    for reading in &input.iot_sensor_data {
+        validate_sensor_reading(reading)?;
+    }
+    Ok(())
+}
+
+const PH_RANGE: std::ops::RangeInclusive<Measure> = Measure::ZERO..=Measure(14 * Measure::SCALE);
+
+/// Range checks for a single IoT sensor reading.
+pub fn validate_sensor_reading(reading: &SensorReading) -> Result<()> {
+    require!(
+        PH_RANGE.contains(&reading.ph_level)
+            && reading.soil_moisture.is_fraction()
+            && reading.humidity.is_fraction(),
+        ErrorCode::ValueOutOfRange
+    );
+    Ok(())
+}
+
+// Declared area may differ from the boundary's computed area by up to 10%
+const AREA_TOLERANCE: BasisPoints = BasisPoints(1_000);
+
+/// Validates a project location and checks the declared `area_hectares`
+/// against the area enclosed by its boundary polygon.
+pub fn validate_location(location: &GeoLocation, area_hectares: Hectares) -> Result<()> {
+    require!(
+        is_valid_coordinate(location.longitude, location.latitude),
+        ErrorCode::InvalidCoordinates
+    );
+    require!(
+        is_iso_3166_alpha2(&location.country_code),
+        ErrorCode::InvalidCountryCode
+    );
+
+    let ring = &location.polygon_coordinates;
+    require!(
+        (GeoLocation::MIN_POLYGON_VERTICES..=GeoLocation::MAX_POLYGON_VERTICES).contains(&ring.len())
+            && ring.first() == ring.last(),
+        ErrorCode::InvalidPolygon
+    );
+    for &[longitude, latitude] in ring {
+        require!(is_valid_coordinate(longitude, latitude), ErrorCode::InvalidCoordinates);
+    }
+    require!(!is_self_intersecting(ring), ErrorCode::SelfIntersectingPolygon);
+
+    let boundary_area = location.polygon_area();
+    require!(boundary_area > Hectares::ZERO, ErrorCode::InvalidPolygon);
+    let difference = area_hectares.0.abs_diff(boundary_area.0) as u128;
+    let allowed = boundary_area.0 as u128 * AREA_TOLERANCE.0 as u128
+        / BasisPoints::HUNDRED_PERCENT.0 as u128;
+    require!(difference <= allowed, ErrorCode::AreaMismatch);
+
+    Ok(())
+}
+
+fn is_valid_coordinate(longitude: Degrees, latitude: Degrees) -> bool {
+    (Degrees::from_whole(-180)..=Degrees::from_whole(180)).contains(&longitude)
+        && (Degrees::from_whole(-90)..=Degrees::from_whole(90)).contains(&latitude)
+}
+
+/// Checks every pair of edges of a closed ring. Repeated vertices count as
+/// intersections.
+fn is_self_intersecting(ring: &[[Degrees; 2]]) -> bool {
+    let edges = ring.len() - 1;
+    for i in 0..edges {
+        for j in i + 1..edges {
+            let adjacent = j == i + 1 || (i == 0 && j == edges - 1);
+            if adjacent {
+                // Adjacent edges only share their common vertex unless they
+                // fold back onto each other
+                let (a, b, c) = if j == i + 1 {
+                    (ring[i], ring[i + 1], ring[j + 1])
+                } else {
+                    (ring[j], ring[0], ring[1])
+                };
+                if orientation(a, b, c) == 0 && (on_segment(a, b, c) || on_segment(b, c, a)) {
+                    return true;
+                }
+            } else if segments_intersect(ring[i], ring[i + 1], ring[j], ring[j + 1]) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+// Officially assigned ISO 3166-1 alpha-2 codes
+const ISO_3166_ALPHA2: &str = "\
+    ADAEAFAGAIALAMAOAQARASATAUAWAXAZBABBBDBEBFBGBHBIBJBLBMBNBOBQBRBSBTBVBWBYBZ\
+    CACCCDCFCGCHCICKCLCMCNCOCRCUCVCWCXCYCZDEDJDKDMDODZECEEEGEHERESETFIFJFKFMFO\
+    FRGAGBGDGEGFGGGHGIGLGMGNGPGQGRGSGTGUGWGYHKHMHNHRHTHUIDIEILIMINIOIQIRISITJE\
+    JMJOJPKEKGKHKIKMKNKPKRKWKYKZLALBLCLILKLRLSLTLULVLYMAMCMDMEMFMGMHMKMLMMMNMO\
+    MPMQMRMSMTMUMVMWMXMYMZNANCNENFNGNINLNONPNRNUNZOMPAPEPFPGPHPKPLPMPNPRPSPTPW\
+    PYQARERORSRURWSASBSCSDSESGSHSISJSKSLSMSNSOSRSSSTSVSXSYSZTCTDTFTGTHTJTKTLTM\
+    TNTOTRTTTVTWTZUAUGUMUSUYUZVAVCVEVGVIVNVUWFWSYEYTZAZMZW";
+
+fn is_iso_3166_alpha2(code: &str) -> bool {
+    code.len() == 2
+        && ISO_3166_ALPHA2
+            .as_bytes()
+            .chunks(2)
+            .any(|assigned| assigned == code.as_bytes())
+}
+
+/// Checks a programme's eligibility criteria are well formed: known
+/// country codes, a valid boundary ring if one is given, and ordered area
+/// and percentage limits.
+pub fn validate_eligibility_criteria(criteria: &EligibilityCriteria) -> Result<()> {
+    validate_max_entries(criteria.country_codes.len(), EligibilityCriteria::MAX_COUNTRIES)?;
+    for code in &criteria.country_codes {
+        require!(is_iso_3166_alpha2(code), ErrorCode::InvalidCountryCode);
+    }
+
+    let boundary = &criteria.boundary;
+    if !boundary.is_empty() {
+        require!(
+            (GeoLocation::MIN_POLYGON_VERTICES..=GeoLocation::MAX_POLYGON_VERTICES)
+                .contains(&boundary.len())
+                && boundary.first() == boundary.last(),
+            ErrorCode::InvalidPolygon
+        );
+        for &[longitude, latitude] in boundary {
+            require!(is_valid_coordinate(longitude, latitude), ErrorCode::InvalidCoordinates);
+        }
+        require!(!is_self_intersecting(boundary), ErrorCode::SelfIntersectingPolygon);
+    }
+
+    require!(
+        criteria.min_site_area <= criteria.max_site_area,
+        ErrorCode::InvalidEligibilityCriteria
+    );
+    require!(
+        criteria.max_uncertainty.is_fraction() && criteria.max_leakage.is_fraction(),
+        ErrorCode::InvalidEligibilityCriteria
+    );
+    Ok(())
+}
+
+/// Checks a prospective programme site against the programme's criteria.
+pub fn validate_site_eligibility(
+    criteria: &EligibilityCriteria,
+    location: &GeoLocation,
+    area_hectares: Hectares,
+    uncertainty_percentage: BasisPoints,
+    leakage_assessment: BasisPoints,
+) -> Result<()> {
+    require!(
+        criteria.country_codes.is_empty() || criteria.country_codes.contains(&location.country_code),
+        ErrorCode::SiteNotEligible
+    );
+    require!(
+        criteria.boundary.is_empty()
+            || ring_within(&location.polygon_coordinates, &criteria.boundary),
+        ErrorCode::SiteNotEligible
+    );
+    require!(
+        (criteria.min_site_area..=criteria.max_site_area).contains(&area_hectares),
+        ErrorCode::SiteNotEligible
+    );
+    require!(
+        uncertainty_percentage <= criteria.max_uncertainty
+            && leakage_assessment <= criteria.max_leakage,
+        ErrorCode::SiteNotEligible
+    );
+    Ok(())
+}
+
+/// Checks a methodology before it is published: seed-sized identifiers,
+/// distinct eligible ecosystems with at most one emission factor each, a
+/// monitoring interval, and percentages that are fractions.
+pub fn validate_methodology_data(data: &MethodologyData, max_cid_len: u16) -> Result<()> {
+    require!(
+        !data.methodology_id.is_empty() && !data.methodology_version.is_empty(),
+        ErrorCode::InvalidMethodology
+    );
+    validate_len(&data.methodology_id, Methodology::ID_LEN)?;
+    validate_len(&data.methodology_version, Methodology::VERSION_LEN)?;
+    validate_cid(&data.document_cid, max_cid_len)?;
+
+    let ecosystems = &data.eligible_ecosystems;
+    require!(!ecosystems.is_empty(), ErrorCode::InvalidMethodology);
+    validate_max_entries(ecosystems.len(), Methodology::MAX_ECOSYSTEMS)?;
+    for (i, ecosystem) in ecosystems.iter().enumerate() {
+        require!(!ecosystems[..i].contains(ecosystem), ErrorCode::InvalidMethodology);
+    }
+
+    let factors = &data.emission_factors;
+    validate_max_entries(factors.len(), Methodology::MAX_ECOSYSTEMS)?;
+    for (i, factor) in factors.iter().enumerate() {
+        require!(
+            ecosystems.contains(&factor.ecosystem_type)
+                && !factors[..i]
+                    .iter()
+                    .any(|other| other.ecosystem_type == factor.ecosystem_type),
+            ErrorCode::InvalidMethodology
+        );
+    }
+
+    require!(data.monitoring_interval_days > 0, ErrorCode::InvalidMethodology);
+    require!(
+        data.buffer_percentage.is_fraction()
+            && data.buffer_percentage < BasisPoints::HUNDRED_PERCENT
+            && data.max_uncertainty.is_fraction()
+            && data.max_leakage.is_fraction()
+            && data.uncertainty_allowance.is_fraction()
+            && data.uncertainty_allowance <= data.max_uncertainty,
+        ErrorCode::InvalidMethodology
+    );
+    Ok(())
+}
+
+/// Checks a project's stated methodology, ecosystem and carbon science
+/// figures against the methodology account it is registered under.
+pub fn validate_methodology_conformance(
+    methodology: &Methodology,
+    vcs_methodology: &str,
+    ecosystem_type: &EcosystemType,
+    uncertainty_percentage: BasisPoints,
+    leakage_assessment: BasisPoints,
+) -> Result<()> {
+    require!(
+        vcs_methodology == methodology.methodology_id,
+        ErrorCode::MethodologyNotConformant
+    );
+    require!(
+        methodology.is_eligible(ecosystem_type),
+        ErrorCode::MethodologyNotConformant
+    );
+    require!(
+        uncertainty_percentage <= methodology.max_uncertainty
+            && leakage_assessment <= methodology.max_leakage,
+        ErrorCode::MethodologyNotConformant
+    );
+    Ok(())
+}
+
+/// Sanity checks for admin-supplied protocol parameters.
+pub fn validate_protocol_params(params: &ProtocolParams) -> Result<()> {
+    require!(params.tokens_per_ton > 0, ErrorCode::InvalidConfig);
+    require!(
+        params.min_quality_rating >= 1 && params.min_quality_rating <= params.max_quality_rating,
+        ErrorCode::InvalidConfig
+    );
+    require!(params.crediting_period_years > 0, ErrorCode::InvalidConfig);
+    require!(params.buffer_bps < 10_000, ErrorCode::InvalidConfig);
+    require!(
+        params.max_project_id_len as usize <= Project::ID_LEN
+            && params.max_cid_len as usize <= Project::CID_LEN
+            && params.max_methodology_len as usize <= Project::METHODOLOGY_LEN
+            && params.max_name_len as usize <= Project::NAME_LEN,
+        ErrorCode::InvalidConfig
+    );
+    require!(
+        (1..=GeoCell::MAX_CELLS_PER_PROJECT).contains(&(params.max_geo_cells_per_project as usize))
+            && (1..=GeoCell::MAX_PROJECTS).contains(&(params.max_projects_per_geo_cell as usize)),
+        ErrorCode::InvalidConfig
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // sha2-256("blue carbon") as CIDv0 and as CIDv1 under each multibase
+    const CID_V0: &str = "QmPreNgx9aufhZdkSzcBtvfe2eQYtNF3s2qL5u3LCR9VRH";
+    const CID_V1_DAG_PB: &str = "bafybeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqir2a";
+    const CID_V1_RAW_BASE58: &str = "zb2rhYAGrkAPSAgekVJS6NUaq46nv83brZnmodQPZvuxh7fd1";
+    const CID_V1_DAG_CBOR_UPPER: &str = "BAFYREIAWRK5Y7764SNT6B4FTALAXINGLTRVTS2C2I657E4S5HXTSUQIR2A";
+
+    #[test]
+    fn parse_cid_accepts_v0_and_v1() {
+        for cid in [CID_V0, CID_V1_DAG_PB, CID_V1_RAW_BASE58, CID_V1_DAG_CBOR_UPPER] {
+            assert!(parse_cid(cid).is_some(), "{cid}");
+        }
+    }
+
+    #[test]
+    fn parse_cid_accepts_multi_byte_hash_function() {
+        // blake2b-256 is multihash 0xb220, a three byte varint
+        assert!(parse_cid("bafk2bzacealivo4p77ojgz7a6czqfqlugtfzy2zznbnepo7sojot3zzkiei5a").is_some());
+    }
+
+    #[test]
+    fn parse_cid_rejects_malformed_v0() {
+        // Wrong length
+        assert!(parse_cid(&CID_V0[..45]).is_none());
+        // '0', 'O', 'I' and 'l' are not in the base58 alphabet
+        assert!(parse_cid("QmPreNgx9aufhZdkSzcBtvfe2eQYtNF3s2qL5u3LCR9VR0").is_none());
+        assert!(parse_cid("QmPreNgx9aufhZdkSzcBtvfe2eQYtNF3s2qL5u3LCR9VRl").is_none());
+    }
+
+    #[test]
+    fn parse_cid_rejects_malformed_v1() {
+        let cases = [
+            // Unsupported multibase (base16)
+            "f01701220",
+            // Unknown content codec 0x60
+            "bafqbeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqir2a",
+            // Digest one byte short
+            "bafybeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqir",
+            // Version 2
+            "bajybeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqir2a",
+            // sha2-512 is not an accepted hash function
+            "bafybgqfevpkejdcjkywyfaiv2e5b7thksj7vfngviwjjp6fuhzbnvcjdrpatmjxehxftrxnqqjeisj7msbh3iicxiq4yh2efqulz2ucvdl7ge",
+            // Mixed case base32
+            "bafybeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqiR2a",
+            "",
+            "b",
+        ];
+        for cid in cases {
+            assert!(parse_cid(cid).is_none(), "{cid}");
+        }
+    }
+
+    #[test]
+    fn base32_rejects_non_zero_trailing_bits() {
+        assert_eq!(decode_base32("me", BASE32_LOWER), Some(vec![0x61]));
+        assert_eq!(decode_base32("mf", BASE32_LOWER), None);
+    }
+
+    #[test]
+    fn base58_keeps_leading_zero_bytes() {
+        assert_eq!(decode_base58("112"), Some(vec![0, 0, 1]));
+        assert_eq!(decode_base58(""), Some(vec![]));
+    }
+
+    #[test]
+    fn read_varint_decodes_leb128() {
+        let mut input: &[u8] = &[0xa0, 0xe4, 0x02, 0x20];
+        assert_eq!(read_varint(&mut input), Some(0xb220));
+        assert_eq!(input, &[0x20]);
+
+        // Continuation bit set on the last byte
+        let mut input: &[u8] = &[0x80, 0x80];
+        assert_eq!(read_varint(&mut input), None);
+    }
+
+    #[test]
+    fn validate_cid_enforces_max_len() {
+        assert!(validate_cid(CID_V0, 64).is_ok());
+        assert!(validate_cid(CID_V0, 45).is_err());
+    }
+
+    // 0.001° in raw Degrees
+    const MILLI: i32 = 10_000;
+
+    fn ring(points: &[(i32, i32)]) -> Vec<[Degrees; 2]> {
+        points.iter().map(|&(lon, lat)| [Degrees(lon), Degrees(lat)]).collect()
+    }
+
+    fn location(polygon_coordinates: Vec<[Degrees; 2]>) -> GeoLocation {
+        let [longitude, latitude] = polygon_coordinates[0];
+        GeoLocation {
+            latitude,
+            longitude,
+            polygon_coordinates,
+            country_code: "ID".to_string(),
+            region_name: String::new(),
+        }
+    }
+
+    // 0.01° square on the equator, about 123.09 ha
+    fn square() -> Vec<[Degrees; 2]> {
+        let side = 10 * MILLI;
+        ring(&[(0, 0), (side, 0), (side, side), (0, side), (0, 0)])
+    }
+
+    fn error(code: ErrorCode) -> Error {
+        code.into()
+    }
+
+    #[test]
+    fn validate_protocol_params_caps_spatial_index_limits() {
+        let mut params = ProtocolParams::default();
+        assert!(validate_protocol_params(&params).is_ok());
+
+        params.max_geo_cells_per_project = GeoCell::MAX_CELLS_PER_PROJECT as u8;
+        params.max_projects_per_geo_cell = GeoCell::MAX_PROJECTS as u8;
+        assert!(validate_protocol_params(&params).is_ok());
+
+        for (cells, projects) in [
+            (GeoCell::MAX_CELLS_PER_PROJECT as u8 + 1, 8),
+            (16, GeoCell::MAX_PROJECTS as u8 + 1),
+            (0, 8),
+            (16, 0),
+        ] {
+            params.max_geo_cells_per_project = cells;
+            params.max_projects_per_geo_cell = projects;
+            assert_eq!(validate_protocol_params(&params), Err(error(ErrorCode::InvalidConfig)));
+        }
+    }
+
+    #[test]
+    fn validate_location_accepts_declared_area_within_tolerance() {
+        let location = location(square());
+        let area = location.polygon_area();
+        assert_eq!(area, Hectares(1_230_907));
+
+        let allowed = area.0 * AREA_TOLERANCE.0 as u64 / BasisPoints::HUNDRED_PERCENT.0 as u64;
+        assert!(validate_location(&location, area).is_ok());
+        assert!(validate_location(&location, Hectares(area.0 + allowed)).is_ok());
+        assert!(validate_location(&location, Hectares(area.0 - allowed)).is_ok());
+        assert_eq!(
+            validate_location(&location, Hectares(area.0 + allowed + 1)).unwrap_err(),
+            error(ErrorCode::AreaMismatch)
+        );
+        assert_eq!(
+            validate_location(&location, Hectares(area.0 - allowed - 1)).unwrap_err(),
+            error(ErrorCode::AreaMismatch)
+        );
+    }
+
+    #[test]
+    fn validate_location_requires_a_closed_ring() {
+        let mut open = square();
+        open.pop();
+        assert_eq!(
+            validate_location(&location(open), Hectares(1_230_907)).unwrap_err(),
+            error(ErrorCode::InvalidPolygon)
+        );
+
+        // Closed but only two distinct vertices
+        let degenerate = ring(&[(0, 0), (MILLI, 0), (0, 0)]);
+        assert_eq!(
+            validate_location(&location(degenerate), Hectares(1)).unwrap_err(),
+            error(ErrorCode::InvalidPolygon)
+        );
+    }
+
+    #[test]
+    fn validate_location_rejects_bowtie() {
+        let side = 10 * MILLI;
+        let bowtie = ring(&[(0, 0), (side, side), (side, 0), (0, side), (0, 0)]);
+        assert!(is_self_intersecting(&bowtie));
+        assert_eq!(
+            validate_location(&location(bowtie), Hectares(1_230_907)).unwrap_err(),
+            error(ErrorCode::SelfIntersectingPolygon)
+        );
+    }
+
+    #[test]
+    fn is_self_intersecting_checks_adjacent_and_repeated_vertices() {
+        let side = 10 * MILLI;
+        assert!(!is_self_intersecting(&square()));
+        // Edge folding back over the previous one
+        assert!(is_self_intersecting(&ring(&[(0, 0), (side, 0), (side / 2, 0), (0, side), (0, 0)])));
+        // Vertex visited twice
+        assert!(is_self_intersecting(&ring(&[
+            (0, 0),
+            (side, 0),
+            (side, side),
+            (side, 0),
+            (0, side),
+            (0, 0),
+        ])));
+    }
+
+    #[test]
+    fn validate_location_at_the_antimeridian() {
+        let east = Degrees::from_whole(180).0;
+        let side = 10 * MILLI;
+
+        // Touching 180° is fine
+        let edge = ring(&[(east - side, 0), (east, 0), (east, side), (east - side, side), (east - side, 0)]);
+        assert!(validate_location(&location(edge), Hectares(1_230_907)).is_ok());
+
+        // Past 180° is not a valid longitude
+        let beyond = ring(&[(east - side, 0), (east + 1, 0), (east + 1, side), (east - side, side), (east - side, 0)]);
+        assert_eq!(
+            validate_location(&location(beyond), Hectares(1_230_907)).unwrap_err(),
+            error(ErrorCode::InvalidCoordinates)
+        );
+
+        // Boundaries are not unwrapped across the antimeridian, so a small
+        // site straddling it measures as a band around the globe
+        let half = side / 2;
+        let straddling = ring(&[
+            (east - half, 0),
+            (-east + half, 0),
+            (-east + half, side),
+            (east - half, side),
+            (east - half, 0),
+        ]);
+        assert_eq!(
+            validate_location(&location(straddling), Hectares(1_230_907)).unwrap_err(),
+            error(ErrorCode::AreaMismatch)
+        );
+    }
+}
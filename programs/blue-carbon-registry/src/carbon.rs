@@ -0,0 +1,340 @@
+//! Deterministic carbon accounting: derives creditable tCO2e from a
+//! project's stocks, area, baseline and deductions. Verification caps
+//! verified tons with it and monitoring periods take the same deductions
+//! from measured growth; clients can run the same code or call
+//! estimate_creditable_carbon to see the figure the program will use.
+//!
+//! Everything is integer arithmetic on the fixed-point types, rounding down
+//! at every step so an estimate never overstates what can be credited.
+
+use anchor_lang::prelude::*;
+use crate::fixed::*;
+use crate::models::{Methodology, Project};
+
+// Molar mass ratio of CO2 to carbon, 44/12
+const CO2_PER_CARBON: (u128, u128) = (44, 12);
+
+/// Figures the calculation reads, taken from a project and, where it has
+/// one, its methodology.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CarbonInputs {
+    // Stocks in tC/ha
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon_0_30cm: Tonnes,
+    pub soil_carbon_30_100cm: Tonnes,
+    pub area_hectares: Hectares,
+    // tCO2e/yr across the whole area
+    pub sequestration_rate_annual: Tonnes,
+    pub leakage: BasisPoints,
+    pub uncertainty: BasisPoints,
+    // Uncertainty up to this level is not deducted
+    pub uncertainty_allowance: BasisPoints,
+    // tCO2e the site would hold without the project; zero without a baseline
+    pub baseline_stock: Tonnes,
+}
+
+impl CarbonInputs {
+    /// Reads the project's measurements. With a methodology, stocks the
+    /// project left at zero fall back to the methodology's default factors
+    /// for its ecosystem, and its uncertainty allowance applies; without
+    /// one, the full uncertainty is deducted.
+    pub fn from_project(project: &Project, methodology: Option<&Methodology>) -> Self {
+        let mut inputs = Self {
+            above_ground_biomass: project.above_ground_biomass,
+            below_ground_biomass: project.below_ground_biomass,
+            soil_carbon_0_30cm: project.soil_carbon_0_30cm,
+            soil_carbon_30_100cm: project.soil_carbon_30_100cm,
+            area_hectares: project.area_hectares,
+            sequestration_rate_annual: project.sequestration_rate_annual,
+            leakage: project.leakage_assessment,
+            uncertainty: project.uncertainty_percentage,
+            uncertainty_allowance: BasisPoints::ZERO,
+            baseline_stock: project
+                .baseline
+                .as_ref()
+                .map_or(Tonnes::ZERO, |baseline| baseline.carbon_stock),
+        };
+
+        if let Some(methodology) = methodology {
+            inputs.uncertainty_allowance = methodology.uncertainty_allowance;
+            if let Some(factor) = methodology.emission_factor(&project.ecosystem_type) {
+                if inputs.above_ground_biomass == Tonnes::ZERO {
+                    inputs.above_ground_biomass = factor.above_ground_biomass;
+                }
+                if inputs.below_ground_biomass == Tonnes::ZERO {
+                    inputs.below_ground_biomass = factor.below_ground_biomass;
+                }
+                // The default covers the whole soil profile
+                if inputs.soil_carbon_0_30cm == Tonnes::ZERO && inputs.soil_carbon_30_100cm == Tonnes::ZERO {
+                    inputs.soil_carbon_0_30cm = factor.soil_carbon;
+                }
+            }
+        }
+
+        inputs
+    }
+}
+
+/// Result of the calculation, with each deduction broken out.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CarbonEstimate {
+    // tC held across the whole area
+    pub carbon_stock: Tonnes,
+    // carbon_stock converted to tCO2e
+    pub gross_co2e: Tonnes,
+    pub baseline_stock: Tonnes,
+    pub leakage_deduction: Tonnes,
+    pub uncertainty_deduction: Tonnes,
+    // tCO2e that can be credited: gross above the baseline, less both
+    // deductions
+    pub creditable: Tonnes,
+    // tCO2e/yr of sequestration after the same deductions
+    pub annual_creditable: Tonnes,
+}
+
+impl CarbonEstimate {
+    /// Creditable tCO2e in the whole tons verification works in.
+    pub fn creditable_tons(&self) -> u64 {
+        self.creditable.0 / Tonnes::SCALE
+    }
+}
+
+/// Leakage and uncertainty deductions taken from a gross tCO2e amount.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Deductions {
+    pub leakage: Tonnes,
+    pub uncertainty: Tonnes,
+    pub net: Tonnes,
+}
+
+/// Runs the calculation, or returns `None` if an intermediate value
+/// overflows or a deduction is outside 0%..=100%.
+pub fn estimate(inputs: &CarbonInputs) -> Option<CarbonEstimate> {
+    let carbon_stock = carbon_stock(inputs)?;
+    let gross_co2e = to_co2e(carbon_stock)?;
+    let above_baseline = gross_co2e.checked_sub(inputs.baseline_stock).unwrap_or_default();
+    let deductions = deduct(above_baseline, inputs)?;
+    let annual = deduct(inputs.sequestration_rate_annual, inputs)?;
+
+    Some(CarbonEstimate {
+        carbon_stock,
+        gross_co2e,
+        baseline_stock: inputs.baseline_stock,
+        leakage_deduction: deductions.leakage,
+        uncertainty_deduction: deductions.uncertainty,
+        creditable: deductions.net,
+        annual_creditable: annual.net,
+    })
+}
+
+/// Gross tCO2e held across the project area.
+pub fn gross_co2e(inputs: &CarbonInputs) -> Option<Tonnes> {
+    to_co2e(carbon_stock(inputs)?)
+}
+
+/// Takes leakage, then uncertainty beyond the allowance, off `gross`.
+pub fn deduct(gross: Tonnes, inputs: &CarbonInputs) -> Option<Deductions> {
+    let uncertainty_rate = BasisPoints(
+        inputs
+            .uncertainty
+            .0
+            .saturating_sub(inputs.uncertainty_allowance.0)
+            .max(0),
+    );
+    if !inputs.leakage.is_fraction() || !uncertainty_rate.is_fraction() {
+        return None;
+    }
+
+    let leakage = share(gross.0, inputs.leakage)?;
+    let after_leakage = gross.0 - leakage;
+    let uncertainty = share(after_leakage, uncertainty_rate)?;
+
+    Some(Deductions {
+        leakage: Tonnes(leakage),
+        uncertainty: Tonnes(uncertainty),
+        net: Tonnes(after_leakage - uncertainty),
+    })
+}
+
+/// tC across the whole area.
+fn carbon_stock(inputs: &CarbonInputs) -> Option<Tonnes> {
+    let stock_per_hectare = [
+        inputs.above_ground_biomass,
+        inputs.below_ground_biomass,
+        inputs.soil_carbon_0_30cm,
+        inputs.soil_carbon_30_100cm,
+    ]
+    .iter()
+    .try_fold(0u128, |total, stock| total.checked_add(stock.0 as u128))?;
+    tonnes(stock_per_hectare.checked_mul(inputs.area_hectares.0 as u128)? / Hectares::SCALE as u128)
+}
+
+fn to_co2e(carbon: Tonnes) -> Option<Tonnes> {
+    tonnes(carbon.0 as u128 * CO2_PER_CARBON.0 / CO2_PER_CARBON.1)
+}
+
+/// `rate` of `amount`, rounded up so the remainder rounds down.
+/// `rate` must already be a fraction.
+fn share(amount: u64, rate: BasisPoints) -> Option<u64> {
+    let hundred_percent = BasisPoints::HUNDRED_PERCENT.0 as u128;
+    u64::try_from((amount as u128 * rate.0 as u128).div_ceil(hundred_percent)).ok()
+}
+
+fn tonnes(raw: u128) -> Option<Tonnes> {
+    u64::try_from(raw).ok().map(Tonnes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EcosystemType, EmissionFactor};
+
+    fn whole(tonnes: u64) -> Tonnes {
+        Tonnes(tonnes * Tonnes::SCALE)
+    }
+
+    // 200 tC/ha over 10 ha, i.e. 2 000 tC
+    fn inputs() -> CarbonInputs {
+        CarbonInputs {
+            above_ground_biomass: whole(100),
+            below_ground_biomass: whole(20),
+            soil_carbon_0_30cm: whole(50),
+            soil_carbon_30_100cm: whole(30),
+            area_hectares: Hectares(10 * Hectares::SCALE),
+            sequestration_rate_annual: whole(100),
+            ..CarbonInputs::default()
+        }
+    }
+
+    #[test]
+    fn converts_carbon_to_co2e_at_44_over_12() {
+        assert_eq!(to_co2e(whole(12)), Some(whole(44)));
+        assert_eq!(to_co2e(whole(3)), Some(whole(11)));
+        // 3.666… raw units, rounded down
+        assert_eq!(to_co2e(Tonnes(1)), Some(Tonnes(3)));
+        assert_eq!(to_co2e(Tonnes(u64::MAX)), None);
+    }
+
+    #[test]
+    fn estimate_without_deductions() {
+        let estimate = estimate(&inputs()).unwrap();
+        assert_eq!(estimate.carbon_stock, whole(2_000));
+        assert_eq!(estimate.gross_co2e, Tonnes(7_333_333_333));
+        assert_eq!(estimate.creditable, estimate.gross_co2e);
+        assert_eq!(estimate.creditable_tons(), 7_333);
+        assert_eq!(estimate.annual_creditable, whole(100));
+        assert_eq!(gross_co2e(&inputs()), Some(estimate.gross_co2e));
+    }
+
+    #[test]
+    fn estimate_takes_baseline_then_leakage_then_excess_uncertainty() {
+        let inputs = CarbonInputs {
+            baseline_stock: Tonnes(1_333_333_333),
+            leakage: BasisPoints(1_000),
+            uncertainty: BasisPoints(1_500),
+            uncertainty_allowance: BasisPoints(1_000),
+            ..inputs()
+        };
+        let estimate = estimate(&inputs).unwrap();
+
+        // 6 000 above the baseline, 10% leakage, then 5% of the remainder
+        assert_eq!(estimate.leakage_deduction, whole(600));
+        assert_eq!(estimate.uncertainty_deduction, whole(270));
+        assert_eq!(estimate.creditable, whole(5_130));
+        assert_eq!(estimate.creditable_tons(), 5_130);
+        // 100 less 10 less 4.5
+        assert_eq!(estimate.annual_creditable, Tonnes(85_500_000));
+    }
+
+    #[test]
+    fn baseline_above_gross_credits_nothing() {
+        let inputs = CarbonInputs {
+            baseline_stock: whole(10_000),
+            ..inputs()
+        };
+        let estimate = estimate(&inputs).unwrap();
+        assert_eq!(estimate.creditable, Tonnes::ZERO);
+        assert_eq!(estimate.leakage_deduction, Tonnes::ZERO);
+    }
+
+    #[test]
+    fn deductions_round_up() {
+        let inputs = CarbonInputs {
+            leakage: BasisPoints(1),
+            uncertainty: BasisPoints(1),
+            ..CarbonInputs::default()
+        };
+        let deductions = deduct(Tonnes(3), &inputs).unwrap();
+        assert_eq!(deductions.leakage, Tonnes(1));
+        assert_eq!(deductions.uncertainty, Tonnes(1));
+        assert_eq!(deductions.net, Tonnes(1));
+    }
+
+    #[test]
+    fn uncertainty_within_allowance_is_not_deducted() {
+        let inputs = CarbonInputs {
+            uncertainty: BasisPoints(500),
+            uncertainty_allowance: BasisPoints(1_000),
+            ..CarbonInputs::default()
+        };
+        let deductions = deduct(whole(100), &inputs).unwrap();
+        assert_eq!(deductions.uncertainty, Tonnes::ZERO);
+        assert_eq!(deductions.net, whole(100));
+    }
+
+    #[test]
+    fn rejects_deductions_outside_a_fraction_and_overflow() {
+        let over = CarbonInputs {
+            leakage: BasisPoints(10_001),
+            ..inputs()
+        };
+        assert_eq!(estimate(&over), None);
+
+        let negative = CarbonInputs {
+            leakage: BasisPoints(-1),
+            ..inputs()
+        };
+        assert_eq!(estimate(&negative), None);
+
+        let huge = CarbonInputs {
+            above_ground_biomass: Tonnes(u64::MAX),
+            area_hectares: Hectares(u64::MAX),
+            ..inputs()
+        };
+        assert_eq!(estimate(&huge), None);
+    }
+
+    #[test]
+    fn from_project_falls_back_to_methodology_factors() {
+        let project = Project {
+            ecosystem_type: EcosystemType::Mangrove,
+            below_ground_biomass: whole(20),
+            area_hectares: Hectares(Hectares::SCALE),
+            uncertainty_percentage: BasisPoints(1_500),
+            ..Project::default()
+        };
+        let methodology = Methodology {
+            emission_factors: vec![EmissionFactor {
+                ecosystem_type: EcosystemType::Mangrove,
+                above_ground_biomass: whole(90),
+                below_ground_biomass: whole(40),
+                soil_carbon: whole(300),
+                sequestration_rate_annual: whole(6),
+            }],
+            uncertainty_allowance: BasisPoints(1_000),
+            ..Methodology::default()
+        };
+
+        let inputs = CarbonInputs::from_project(&project, Some(&methodology));
+        assert_eq!(inputs.above_ground_biomass, whole(90));
+        // Measured stocks win over the default
+        assert_eq!(inputs.below_ground_biomass, whole(20));
+        assert_eq!(inputs.soil_carbon_0_30cm, whole(300));
+        assert_eq!(inputs.uncertainty_allowance, BasisPoints(1_000));
+
+        let without = CarbonInputs::from_project(&project, None);
+        assert_eq!(without.above_ground_biomass, Tonnes::ZERO);
+        assert_eq!(without.uncertainty_allowance, BasisPoints::ZERO);
+    }
+}
@@ -0,0 +1,186 @@
+//! Fixed-point quantities used in account and instruction data in place of
+//! floating point.
+//!
+//! Each type wraps an integer count of its smallest unit, so totals
+//! reconcile exactly and NaN or infinity cannot be represented. Clients
+//! convert with `from_f64`, which rejects non-finite and out-of-range values.
+
+use anchor_lang::prelude::*;
+
+macro_rules! fixed_point {
+    ($(#[$doc:meta])* $name:ident($inner:ty), $scale:expr) => {
+        $(#[$doc])*
+        #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name(pub $inner);
+
+        impl $name {
+            /// Raw units per whole unit.
+            pub const SCALE: $inner = $scale;
+            pub const ZERO: Self = Self(0);
+
+            /// Converts from whole units, rounding to the nearest raw unit.
+            pub fn from_f64(value: f64) -> Option<Self> {
+                let scaled = (value * Self::SCALE as f64).round();
+                // MAX as f64 rounds up to a power of two for 64-bit types,
+                // so compare against the first value past MAX instead
+                (scaled.is_finite() && scaled >= <$inner>::MIN as f64 && scaled < <$inner>::MAX as f64 + 1.0)
+                    .then(|| Self(scaled as $inner))
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::SCALE as f64
+            }
+
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Self)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let scale = Self::SCALE as i128;
+                let raw = self.0 as i128;
+                let sign = if raw < 0 { "-" } else { "" };
+                let digits = scale.ilog10() as usize;
+                write!(f, "{}{}.{:0digits$}", sign, raw.abs() / scale, raw.abs() % scale)
+            }
+        }
+    };
+}
+
+fixed_point!(
+    /// Mass in tonnes (tCO2e or tC, as documented on the field) × 10^6,
+    /// i.e. gram resolution.
+    Tonnes(u64),
+    1_000_000
+);
+
+fixed_point!(
+    /// Area in hectares × 10^4, i.e. square metre resolution.
+    Hectares(u64),
+    10_000
+);
+
+fixed_point!(
+    /// Percentage in basis points: 1% = 100, 100% = 10 000. Signed so that
+    /// changes and returns can be negative.
+    BasisPoints(i32),
+    100
+);
+
+fixed_point!(
+    /// Signed measurement in the unit documented on the field × 10^6
+    /// (indices, pH, concentrations, temperatures, lengths, years).
+    Measure(i64),
+    1_000_000
+);
+
+fixed_point!(
+    /// Latitude or longitude in degrees × 10^7, about 1 cm resolution.
+    Degrees(i32),
+    10_000_000
+);
+
+impl BasisPoints {
+    pub const HUNDRED_PERCENT: Self = Self(10_000);
+
+    /// Whether this lies in 0%..=100%.
+    pub fn is_fraction(self) -> bool {
+        (0..=Self::HUNDRED_PERCENT.0).contains(&self.0)
+    }
+
+    /// Relative change from `before` to `after`, or `None` when `before` is
+    /// zero or the result does not fit.
+    pub fn change(before: u64, after: u64) -> Option<Self> {
+        if before == 0 {
+            return None;
+        }
+        let delta = after as i128 - before as i128;
+        i32::try_from(delta * Self::HUNDRED_PERCENT.0 as i128 / before as i128)
+            .ok()
+            .map(Self)
+    }
+}
+
+impl Degrees {
+    pub const fn from_whole(degrees: i32) -> Self {
+        Self(degrees * Self::SCALE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_f64_rounds_to_nearest_raw_unit() {
+        assert_eq!(Tonnes::from_f64(1.5), Some(Tonnes(1_500_000)));
+        assert_eq!(Tonnes::from_f64(0.000_000_4), Some(Tonnes(0)));
+        assert_eq!(Tonnes::from_f64(0.000_000_6), Some(Tonnes(1)));
+        // Halfway rounds away from zero
+        assert_eq!(BasisPoints::from_f64(12.345), Some(BasisPoints(1_235)));
+        assert_eq!(BasisPoints::from_f64(-0.005), Some(BasisPoints(-1)));
+        assert_eq!(Degrees::from_f64(-6.208_800_05), Some(Degrees(-62_088_001)));
+    }
+
+    #[test]
+    fn from_f64_rejects_non_finite_and_out_of_range() {
+        assert_eq!(Measure::from_f64(f64::NAN), None);
+        assert_eq!(Measure::from_f64(f64::INFINITY), None);
+        assert_eq!(Measure::from_f64(f64::NEG_INFINITY), None);
+        // Unsigned types have no negative values
+        assert_eq!(Hectares::from_f64(-0.01), None);
+        assert_eq!(Hectares::from_f64(-0.000_01), Some(Hectares(0)));
+        // i32 bounds are exact
+        assert_eq!(BasisPoints::from_f64(21_474_836.47), Some(BasisPoints(i32::MAX)));
+        assert_eq!(BasisPoints::from_f64(21_474_836.48), None);
+        assert_eq!(BasisPoints::from_f64(-21_474_836.48), Some(BasisPoints(i32::MIN)));
+        assert_eq!(BasisPoints::from_f64(-21_474_836.49), None);
+        assert_eq!(Degrees::from_f64(215.0), None);
+        // 2^64 raw units must not saturate to u64::MAX
+        assert_eq!(Tonnes::from_f64(2f64.powi(64) / Tonnes::SCALE as f64), None);
+        assert_eq!(Tonnes::from_f64(1e13), Some(Tonnes(10_000_000_000_000_000_000)));
+    }
+
+    #[test]
+    fn checked_arithmetic_detects_overflow() {
+        assert_eq!(Tonnes(1).checked_add(Tonnes(2)), Some(Tonnes(3)));
+        assert_eq!(Tonnes(u64::MAX).checked_add(Tonnes(1)), None);
+        assert_eq!(Tonnes(1).checked_sub(Tonnes(2)), None);
+        assert_eq!(BasisPoints(-5).checked_sub(BasisPoints(10)), Some(BasisPoints(-15)));
+        assert_eq!(BasisPoints(i32::MIN).checked_sub(BasisPoints(1)), None);
+        assert_eq!(Measure(i64::MAX).checked_add(Measure(1)), None);
+    }
+
+    #[test]
+    fn display_pads_the_fraction() {
+        assert_eq!(Tonnes(1_000_050).to_string(), "1.000050");
+        assert_eq!(Hectares(5).to_string(), "0.0005");
+        assert_eq!(BasisPoints(-1).to_string(), "-0.01");
+        assert_eq!(Degrees(-62_088_000).to_string(), "-6.2088000");
+        assert_eq!(Measure(i64::MIN).to_string(), "-9223372036854.775808");
+    }
+
+    #[test]
+    fn to_f64_round_trips() {
+        for value in [0.0, 1.25, 123.456_789] {
+            assert_eq!(Tonnes::from_f64(value).map(Tonnes::to_f64), Some(value));
+        }
+        assert_eq!(Degrees::from_whole(-180).to_f64(), -180.0);
+    }
+
+    #[test]
+    fn basis_points_change_and_fraction() {
+        assert_eq!(BasisPoints::change(200, 250), Some(BasisPoints(2_500)));
+        assert_eq!(BasisPoints::change(200, 100), Some(BasisPoints(-5_000)));
+        assert_eq!(BasisPoints::change(0, 100), None);
+        assert_eq!(BasisPoints::change(1, u64::MAX), None);
+        assert!(BasisPoints::HUNDRED_PERCENT.is_fraction());
+        assert!(!BasisPoints(10_001).is_fraction());
+        assert!(!BasisPoints(-1).is_fraction());
+    }
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, SetAuthority};
+use crate::models::*;
+use crate::auth_utils::access::{authorize_admin_action, execute_admin_proposal, has_permission};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*, mint_credits::with_mint_authority_signer};
+
+pub fn propose_admin_transfer(ctx: Context<ManageAdminTransfer>, new_admin: Pubkey) -> Result<()> {
+    authorize_registry_admin(ctx.accounts, AdminAction::ProposeAdminTransfer { new_admin })?;
+
+    let registry = &mut ctx.accounts.registry;
+
+    registry.pending_admin = Some(new_admin);
+
+    emit!(AdminTransferProposed {
+        current_admin: registry.admin,
+        pending_admin: new_admin,
+    });
+    msg!("Admin transfer proposed to {}", new_admin);
+
+    Ok(())
+}
+
+pub fn accept_admin_transfer(ctx: Context<ManageAdminTransfer>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let pending_admin = registry.pending_admin.ok_or(ErrorCode::NoPendingAdminTransfer)?;
+    require_keys_eq!(ctx.accounts.authority.key(), pending_admin, ErrorCode::NotPendingAdmin);
+
+    let previous_admin = registry.admin;
+    registry.admin = pending_admin;
+    registry.pending_admin = None;
+
+    emit!(AdminTransferAccepted {
+        previous_admin,
+        new_admin: pending_admin,
+    });
+    msg!("Registry admin changed from {} to {}", previous_admin, pending_admin);
+
+    Ok(())
+}
+
+pub fn cancel_admin_transfer(ctx: Context<ManageAdminTransfer>) -> Result<()> {
+    authorize_registry_admin(ctx.accounts, AdminAction::CancelAdminTransfer)?;
+
+    let registry = &mut ctx.accounts.registry;
+
+    let cancelled_admin = registry.pending_admin.take().ok_or(ErrorCode::NoPendingAdminTransfer)?;
+
+    emit!(AdminTransferCancelled {
+        admin: registry.admin,
+        cancelled_admin,
+    });
+    msg!("Admin transfer to {} cancelled", cancelled_admin);
+
+    Ok(())
+}
+
+/// Admin key in single mode, an approved proposal in multisig mode.
+fn authorize_registry_admin(accounts: &mut ManageAdminTransfer, action: AdminAction) -> Result<()> {
+    authorize_admin_action(
+        &accounts.authority,
+        &accounts.registry,
+        accounts.proposal.as_mut(),
+        action,
+    )
+}
+
+/// Moves mint authority over the carbon credit mint to the
+/// [b"mint_authority", generation] PDA, keeping the registry seeds unchanged.
+pub fn rotate_mint_authority(ctx: Context<RotateMintAuthority>, generation: u16) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.admin,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::RotateMintAuthority { generation },
+    )?;
+
+    let registry = &ctx.accounts.registry;
+    require!(
+        generation > registry.mint_authority_generation,
+        ErrorCode::StaleMintAuthorityGeneration
+    );
+
+    let new_authority = ctx.accounts.new_mint_authority.key();
+    let cpi_accounts = SetAuthority {
+        current_authority: ctx.accounts.current_mint_authority.to_account_info(),
+        account_or_mint: ctx.accounts.carbon_token_mint.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+
+    with_mint_authority_signer(registry, |signer_seeds| {
+        token::set_authority(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
+            AuthorityType::MintTokens,
+            Some(new_authority),
+        )
+    })?;
+
+    let registry = &mut ctx.accounts.registry;
+    let previous_authority = registry.mint_authority;
+    registry.mint_authority = new_authority;
+    registry.mint_authority_bump = ctx.bumps.new_mint_authority;
+    registry.mint_authority_generation = generation;
+
+    emit!(MintAuthorityRotated {
+        previous_authority,
+        new_authority,
+        generation,
+    });
+    msg!("Mint authority rotated to {} (generation {})", new_authority, generation);
+
+    Ok(())
+}
+
+/// Pauses or resumes one subsystem. Pausing is an emergency brake any admin
+/// can pull alone; resuming needs an approved proposal in multisig mode.
+pub fn set_pause(ctx: Context<SetPause>, subsystem: Subsystem, paused: bool) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    if !paused && accounts.registry.multisig_enabled {
+        execute_admin_proposal(
+            &accounts.authority,
+            &accounts.registry,
+            accounts.proposal.as_mut(),
+            &AdminAction::Unpause { subsystem },
+        )?;
+    } else {
+        require!(
+            has_permission(
+                &accounts.authority,
+                Role::Admin,
+                &accounts.registry,
+                accounts.authority_role.as_deref(),
+            ),
+            ErrorCode::MissingRole
+        );
+    }
+
+    let registry = &mut accounts.registry;
+    if paused {
+        registry.paused_subsystems |= subsystem.flag();
+    } else {
+        registry.paused_subsystems &= !subsystem.flag();
+    }
+
+    emit!(PauseStateChanged {
+        subsystem,
+        paused,
+        authority: accounts.authority.key(),
+    });
+    msg!("{:?} paused: {}", subsystem, paused);
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::carbon::{self, CarbonInputs};
+use crate::fixed::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{require_issuing, require_not_paused, validate_cid},
+};
+use crate::instructions::{
+    contexts::*, errors::ErrorCode, events::*, methodology::check_project_methodology,
+    resize::fit_account_space,
+};
+
+/// Owner proposes the carbon baseline credits will be measured against.
+/// It can be revised while the project is pending or rejected, but not
+/// while a verifier is reviewing it; verification locks it.
+pub fn set_carbon_baseline(
+    ctx: Context<SetCarbonBaseline>,
+    carbon_stock: Tonnes,
+    emissions_annual: Tonnes,
+    evidence_cid: String,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+    validate_cid(&evidence_cid, ctx.accounts.config.params.max_cid_len)?;
+
+    let project = &mut ctx.accounts.project;
+    require!(!project.baseline_locked(), ErrorCode::BaselineLocked);
+    require!(
+        matches!(
+            project.verification_status,
+            VerificationStatus::Pending | VerificationStatus::Rejected
+        ),
+        ErrorCode::ProjectNotEditable
+    );
+
+    project.baseline = Some(CarbonBaseline {
+        carbon_stock,
+        emissions_annual,
+        evidence_cid: evidence_cid.clone(),
+        locked_at: 0,
+    });
+
+    emit!(CarbonBaselineSet {
+        project: project.key(),
+        carbon_stock,
+        emissions_annual,
+        evidence_cid,
+    });
+    msg!(
+        "Project {} baseline: {} tCO2e stock, {} tCO2e/yr emissions",
+        project.project_id,
+        carbon_stock,
+        emissions_annual
+    );
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Locks the baseline the first time a project is verified and starts the
+/// first monitoring period. Projects with site data cannot be verified
+/// without one; projects registered through register_project have none.
+pub(crate) fn lock_baseline(project: &mut Account<Project>) -> Result<()> {
+    if project.baseline.is_none() {
+        require!(project.area_hectares == Hectares::ZERO, ErrorCode::BaselineRequired);
+        return Ok(());
+    }
+    if project.baseline_locked() {
+        return Ok(());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let project_key = project.key();
+    let baseline = project.baseline.as_mut().ok_or(ErrorCode::BaselineRequired)?;
+    baseline.locked_at = now;
+    let (carbon_stock, emissions_annual) = (baseline.carbon_stock, baseline.emissions_annual);
+
+    project.credited_stock = carbon_stock;
+    project.last_monitoring_period_end = now;
+
+    emit!(CarbonBaselineLocked {
+        project: project_key,
+        carbon_stock,
+        emissions_annual,
+    });
+    msg!("Project {} baseline locked", project.project_id);
+
+    Ok(())
+}
+
+/// Verifier closes a monitoring period ending at `period_end` and credits
+/// its net removals: growth of the project's gross stock beyond the highest
+/// stock credited so far, plus the baseline emissions avoided over the
+/// period, less leakage and uncertainty deductions. `carbon_stock` is the
+/// gross tCO2e the verifier measured across the project area at the period
+/// end; a stock below what was already credited adds nothing.
+pub fn record_monitoring_period(
+    ctx: Context<RecordMonitoringPeriod>,
+    period_end: i64,
+    carbon_stock: Tonnes,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+    require!(
+        has_permission(
+            &ctx.accounts.verifier,
+            Role::Verifier,
+            &ctx.accounts.registry,
+            ctx.accounts.verifier_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
+    let project = &ctx.accounts.project;
+    require_issuing(&project.verification_status)?;
+    let emissions_annual = match &project.baseline {
+        Some(baseline) if baseline.locked_at != 0 => baseline.emissions_annual,
+        _ => return err!(ErrorCode::BaselineNotLocked),
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let period_start = project.last_monitoring_period_end;
+    require!(
+        period_start < period_end && period_end <= now,
+        ErrorCode::InvalidMonitoringPeriod
+    );
+    require!(
+        period_end <= project.crediting_period_end,
+        ErrorCode::VintageOutsideCreditingPeriod
+    );
+
+    let methodology = check_project_methodology(project, ctx.accounts.methodology.as_ref())?;
+    let inputs = CarbonInputs::from_project(project, methodology);
+    let stock_gain = carbon_stock.checked_sub(project.credited_stock).unwrap_or_default();
+    let avoided_emissions = u64::try_from(
+        emissions_annual.0 as u128 * (period_end - period_start) as u128
+            / Project::SECONDS_PER_YEAR as u128,
+    )
+    .map(Tonnes)
+    .map_err(|_| ErrorCode::ArithmeticOverflow)?;
+    let gross_removals = stock_gain
+        .checked_add(avoided_emissions)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let deductions = carbon::deduct(gross_removals, &inputs).ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let project = &mut ctx.accounts.project;
+    let index = project.monitoring_period_count;
+    project.credited_stock = project.credited_stock.max(carbon_stock);
+    project.net_removals = project
+        .net_removals
+        .checked_add(deductions.net)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    project.last_monitoring_period_end = period_end;
+    project.monitoring_period_count += 1;
+
+    let period = &mut ctx.accounts.monitoring_period;
+    period.project = project.key();
+    period.index = index;
+    period.period_start = period_start;
+    period.period_end = period_end;
+    period.carbon_stock = carbon_stock;
+    period.stock_gain = stock_gain;
+    period.avoided_emissions = avoided_emissions;
+    period.leakage_deduction = deductions.leakage;
+    period.uncertainty_deduction = deductions.uncertainty;
+    period.net_removals = deductions.net;
+    period.recorded_by = ctx.accounts.verifier.key();
+    period.bump = ctx.bumps.monitoring_period;
+
+    emit!(MonitoringPeriodRecorded {
+        project: project.key(),
+        monitoring_period: period.key(),
+        index,
+        period_start,
+        period_end,
+        net_removals: deductions.net,
+        total_net_removals: project.net_removals,
+    });
+    msg!(
+        "Project {} monitoring period #{}: {} tCO2e net removals ({} total)",
+        project.project_id,
+        index,
+        deductions.net,
+        project.net_removals
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::fixed::BasisPoints;
+use crate::auth_utils::{access::has_permission, validation::require_not_paused};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::BufferRateSet, resize::fit_account_space};
+
+/// Verifier records a project's non-permanence risk rating as the share of
+/// each issuance withheld for the buffer pool. `None` clears it, so the
+/// methodology's percentage or the registry default applies again.
+pub fn set_buffer_rate(ctx: Context<SetBufferRate>, rate: Option<BasisPoints>) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+    require!(
+        has_permission(
+            &ctx.accounts.verifier,
+            Role::Verifier,
+            &ctx.accounts.registry,
+            ctx.accounts.verifier_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+    if let Some(rate) = rate {
+        require!(
+            rate.is_fraction() && rate < BasisPoints::HUNDRED_PERCENT,
+            ErrorCode::InvalidBufferRate
+        );
+    }
+
+    let project = &mut ctx.accounts.project;
+    project.buffer_rate = rate;
+
+    emit!(BufferRateSet {
+        project: project.key(),
+        rate,
+        verifier: ctx.accounts.verifier.key(),
+    });
+    msg!("Project {} buffer rate set to {:?}", project.project_id, rate);
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.verifier,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Part of `amount` withheld at `rate`, rounded up in the buffer's favour.
+pub(crate) fn buffer_share(amount: u64, rate: BasisPoints) -> u64 {
+    let hundred_percent = BasisPoints::HUNDRED_PERCENT.0 as u128;
+    (amount as u128 * rate.0.max(0) as u128).div_ceil(hundred_percent) as u64
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{require_not_paused, validate_cid},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*};
+
+/// Withdraws a project that never issued credits, returning the rent of the
+/// project and its ID reservation to the owner and freeing the project ID.
+pub fn deregister_project(ctx: Context<DeregisterProject>) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    let project = &ctx.accounts.project;
+    require!(
+        project.credits_issued == 0 && project.tokens_minted == 0,
+        ErrorCode::ProjectHasCredits
+    );
+
+    let registry = &mut ctx.accounts.registry;
+    registry.total_projects = registry.total_projects.saturating_sub(1);
+
+    emit!(ProjectDeregistered {
+        project: project.key(),
+        project_id: project.project_id.clone(),
+        owner: project.owner,
+    });
+    msg!("Project {} deregistered", project.project_id);
+    msg!("Total projects: {}", registry.total_projects);
+
+    Ok(())
+}
+
+/// Closes a monitoring record whose data has been committed to `archive_cid`.
+pub fn archive_monitoring_data(ctx: Context<ArchiveMonitoringData>, archive_cid: String) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.authority,
+            Role::Auditor,
+            &ctx.accounts.registry,
+            ctx.accounts.authority_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+    validate_cid(&archive_cid, ctx.accounts.config.params.max_cid_len)?;
+
+    let record = &ctx.accounts.monitoring_data;
+    emit!(RecordArchived {
+        record: record.key(),
+        project_id: record.project_id.clone(),
+        archive_cid,
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!("Monitoring data for project {} archived", record.project_id);
+
+    Ok(())
+}
+
+/// Closes an impact report whose data has been committed to `archive_cid`.
+pub fn archive_impact_report(ctx: Context<ArchiveImpactReport>, archive_cid: String) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.authority,
+            Role::Auditor,
+            &ctx.accounts.registry,
+            ctx.accounts.authority_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+    validate_cid(&archive_cid, ctx.accounts.config.params.max_cid_len)?;
+
+    let record = &ctx.accounts.impact_report;
+    emit!(RecordArchived {
+        record: record.key(),
+        project_id: record.project_id.clone(),
+        archive_cid,
+        authority: ctx.accounts.authority.key(),
+    });
+    msg!("Impact report for project {} archived", record.project_id);
+
+    Ok(())
+}
+
+/// Closes a listing that no longer offers credits: deactivated, sold out or
+/// expired. The seller can close their own listing; auditors can sweep
+/// stale ones.
+pub fn close_listing(ctx: Context<CloseListing>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let authority = &ctx.accounts.authority;
+
+    if authority.key() != listing.seller {
+        require!(
+            has_permission(
+                authority,
+                Role::Auditor,
+                &ctx.accounts.registry,
+                ctx.accounts.authority_role.as_deref(),
+            ),
+            ErrorCode::MissingRole
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !listing.is_active || listing.quantity_available == 0 || listing.expiry_date < now,
+        ErrorCode::ListingStillActive
+    );
+
+    msg!("Listing for project {} closed", listing.project_id);
+
+    Ok(())
+}
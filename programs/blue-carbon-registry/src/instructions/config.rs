@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::{authorize_admin_action, execute_admin_proposal},
+    validation::{validate_authority, validate_protocol_params},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*};
+
+/// Creates the registry config PDA with the protocol's default parameters,
+/// and the buffer pool every mint withholds into. Run once after upgrading;
+/// minting is unavailable until it has. `tokens_per_ton` is taken from the
+/// carbon token mint, one whole token per tCO2e.
+pub fn initialize_registry_config(ctx: Context<InitializeRegistryConfig>) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.admin,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::InitializeRegistryConfig,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.params = ProtocolParams {
+        tokens_per_ton: tokens_per_ton(ctx.accounts.carbon_token_mint.decimals)?,
+        ..ProtocolParams::default()
+    };
+    config.bump = ctx.bumps.config;
+
+    msg!("Registry config initialized with default parameters");
+    msg!("Buffer pool initialized: {}", ctx.accounts.buffer_pool.key());
+
+    Ok(())
+}
+
+pub fn update_registry_config(
+    ctx: Context<UpdateRegistryConfig>,
+    params: ProtocolParams,
+) -> Result<()> {
+    validate_protocol_params(&params)?;
+
+    let accounts = ctx.accounts;
+    // Every cap, net removal capacity and reversal liability is counted in
+    // tokens at this rate, so it is fixed from the first issuance on
+    require!(
+        params.tokens_per_ton == accounts.config.params.tokens_per_ton
+            || accounts.registry.total_credits_issued == 0,
+        ErrorCode::TokensPerTonFrozen
+    );
+    if accounts.registry.multisig_enabled {
+        execute_admin_proposal(
+            &accounts.authority,
+            &accounts.registry,
+            accounts.proposal.as_mut(),
+            &AdminAction::UpdateConfig { params: params.clone() },
+        )?;
+    } else {
+        validate_authority(&accounts.authority, &accounts.registry)?;
+    }
+
+    accounts.config.params = params.clone();
+
+    emit!(RegistryConfigUpdated {
+        params,
+        authority: accounts.authority.key(),
+    });
+    msg!("Registry config updated");
+
+    Ok(())
+}
+
+// Base units in one whole token of a mint with `decimals`
+pub(crate) fn tokens_per_ton(decimals: u8) -> Result<u64> {
+    10u64.checked_pow(decimals as u32).ok_or_else(|| error!(ErrorCode::InvalidConfig))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_per_ton_is_one_whole_token() {
+        assert_eq!(tokens_per_ton(0).unwrap(), 1);
+        assert_eq!(tokens_per_ton(6).unwrap(), 1_000_000);
+        assert_eq!(tokens_per_ton(19).unwrap(), 10u64.pow(19));
+        assert_eq!(tokens_per_ton(20), Err(ErrorCode::InvalidConfig.into()));
+    }
+}
@@ -4,6 +4,7 @@ use anchor_spl::{
     associated_token::AssociatedToken,
 };
 use crate::models::*;
+use crate::instructions::errors::ErrorCode;
 
 // Account validation for initialize_registry instruction
 #[derive(Accounts)]
@@ -36,19 +37,150 @@ pub struct InitializeRegistry<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[derive(Accounts)]
+pub struct SetBufferRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub verifier_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReportReversal<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = auditor,
+        space = 8 + Reversal::LEN,
+        seeds = [
+            b"reversal",
+            project.key().as_ref(),
+            &project.reversal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub reversal: Account<'info, Reversal>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = carbon_token_mint
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub carbon_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"buffer_pool"],
+        bump
+    )]
+    pub buffer_pool: Account<'info, TokenAccount>,
+
+    pub auditor_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub auditor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompensateReversal<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = carbon_token_mint
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub carbon_token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = carbon_token_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"buffer_pool"],
+        bump
+    )]
+    pub buffer_pool: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
 // Account validation for the `register_project` instruction
 #[derive(Accounts)]
-#[instruction(project_id: String)]
+#[instruction(project_id: String, ipfs_cid: String)]
 pub struct RegisterProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
     #[account(
         init,
         payer = project_owner,
-        space = 8 + Project::LEN,
+        space = Project::init_space(serialized_len(&project_id) + serialized_len(&ipfs_cid)),
         seeds = [b"project", project_owner.key().as_ref(), project_id.as_bytes()],
         bump
     )]
     pub project: Account<'info, Project>,
 
+    #[account(
+        init,
+        payer = project_owner,
+        space = 8 + ProjectIdIndex::LEN,
+        seeds = [b"project_id", project_id.as_bytes()],
+        bump
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
     #[account(
         mut,
         seeds = [b"registry_v3"],
@@ -65,29 +197,62 @@ pub struct RegisterProject<'info> {
 // Account validation for verify_project instruction
 #[derive(Accounts)]
 pub struct VerifyProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
 
     #[account(
         seeds = [b"registry_v3"],
-        bump = registry.bump,
-        has_one = admin
+        bump = registry.bump
     )]
     pub registry: Account<'info, GlobalRegistry>,
 
+    /// Verifier role assignment, required unless `admin` is the registry admin
+    pub admin_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Required when the project was registered under a methodology
+    pub methodology: Option<Account<'info, Methodology>>,
+
     pub admin: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct EstimateCreditableCarbon<'info> {
+    #[account(
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Required when the project was registered under a methodology
+    pub methodology: Option<Account<'info, Methodology>>,
+}
+
 // Account validation for mint_verified_credits instruction
 #[derive(Accounts)]
 pub struct MintVerifiedCredits<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
         bump = project.bump,
         has_one = owner
     )]
@@ -107,6 +272,10 @@ pub struct MintVerifiedCredits<'info> {
     )]
     pub carbon_token_mint: Account<'info, Mint>,
 
+    /// CHECK: Either the registry PDA or a rotated mint authority PDA; checked against the registry.
+    #[account(address = registry.mint_authority)]
+    pub mint_authority: UncheckedAccount<'info>,
+
     #[account(
         mut,
         associated_token::mint = carbon_token_mint,
@@ -114,6 +283,21 @@ pub struct MintVerifiedCredits<'info> {
     )]
     pub recipient_token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"buffer_pool"],
+        bump
+    )]
+    pub buffer_pool: Account<'info, TokenAccount>,
+
+    /// Approved MintCredits proposal, required once the project has
+    /// proponents
+    #[account(mut)]
+    pub proponent_proposal: Option<Account<'info, ProponentProposal>>,
+
+    /// Required when the project was registered under a methodology
+    pub methodology: Option<Account<'info, Methodology>>,
+
     pub owner: Signer<'info>,
     pub recipient: SystemAccount<'info>,
 
@@ -131,7 +315,7 @@ pub struct MintCredits<'info> {
     #[account(
         mut,
         has_one = owner,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub project: Account<'info, Project>,
@@ -143,9 +327,16 @@ pub struct MintCredits<'info> {
     /// CHECK: This is the recipient token account
     pub recipient_token_account: AccountInfo<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
     /// CHECK: The mint authority is a PDA, so we validate it with seeds.
     #[account(
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
         bump = project.bump
     )]
     pub mint_authority: AccountInfo<'info>,
@@ -163,7 +354,13 @@ pub struct TransferCredits<'info> {
     #[account(mut)]
     /// CHECK: This is the destination token account
     pub to_account: AccountInfo<'info>,
-    
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
     pub from_authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
@@ -178,7 +375,13 @@ pub struct RetireCredits<'info> {
     #[account(mut)]
     /// CHECK: This is a placeholder for a "burn" or "retirement" account.
     pub retirement_account: AccountInfo<'info>,
-    
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
     pub from_authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
@@ -186,6 +389,14 @@ pub struct RetireCredits<'info> {
 // Accounts for trade_credits (placeholder)
 #[derive(Accounts)]
 pub struct TradeCredits<'info> {
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
     pub authority: Signer<'info>,
 }
 
@@ -193,15 +404,34 @@ pub struct TradeCredits<'info> {
 #[derive(Accounts)]
 #[instruction(project_data: BlueProjectData)]
 pub struct RegisterBlueProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
     #[account(
         init,
         payer = project_owner,
-        space = 8 + Project::LEN,
+        space = Project::init_space(serialized_len(&project_data)),
         seeds = [b"project", project_owner.key().as_ref(), project_data.project_id.as_bytes()],
         bump
     )]
     pub project: Account<'info, Project>,
 
+    #[account(
+        init,
+        payer = project_owner,
+        space = 8 + ProjectIdIndex::LEN,
+        seeds = [b"project_id", project_data.project_id.as_bytes()],
+        bump
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    /// Methodology the project registers under, if any
+    pub methodology: Option<Account<'info, Methodology>>,
+
     #[account(
         mut,
         seeds = [b"registry_v3"],
@@ -216,112 +446,1261 @@ pub struct RegisterBlueProject<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(verifier_data: VerifierData)]
-pub struct RegisterVerifier<'info> {
+#[instruction(programme_data: ProgrammeData)]
+pub struct RegisterProgramme<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
     #[account(
         init,
-        payer = verifier_authority,
-        space = 8 + 1000,
-        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        payer = owner,
+        space = Programme::init_space(serialized_len(&programme_data)),
+        seeds = [b"programme", owner.key().as_ref(), programme_data.programme_id.as_bytes()],
         bump
     )]
-    pub verifier: Account<'info, VerificationNode>,
+    pub programme: Account<'info, Programme>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
 
     #[account(mut)]
-    pub verifier_authority: Signer<'info>,
+    pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct MultiPartyVerifyProject<'info> {
+pub struct ValidateProgramme<'info> {
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        seeds = [b"programme", programme.creator.as_ref(), programme.programme_id.as_bytes()],
+        bump = programme.bump
     )]
-    pub project: Account<'info, Project>,
+    pub programme: Account<'info, Programme>,
 
     #[account(
-        mut,
-        seeds = [b"verifier", verifier_authority.key().as_ref()],
-        bump
+        seeds = [b"registry_v3"],
+        bump = registry.bump
     )]
-    pub verifier: Account<'info, VerificationNode>,
+    pub registry: Account<'info, GlobalRegistry>,
 
-    pub verifier_authority: Signer<'info>,
+    pub verifier_role: Option<Account<'info, RoleAssignment>>,
+
+    pub verifier: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(project_id: String, timestamp: i64)]
-pub struct SubmitMonitoringData<'info> {
+#[instruction(site: ProgrammeSiteData)]
+pub struct AddProgrammeSite<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"programme", programme.creator.as_ref(), programme.programme_id.as_bytes()],
+        bump = programme.bump,
+        has_one = owner
+    )]
+    pub programme: Account<'info, Programme>,
+
+    // Sized from the site payload plus the programme it inherits design
+    // fields from, and trimmed to fit in the handler
     #[account(
         init,
-        payer = data_provider,
-        space = 8 + 2000,
-        seeds = [b"monitoring", project_id.as_bytes(), &timestamp.to_le_bytes()],
+        payer = owner,
+        space = Project::init_space(serialized_len(&site) + serialized_len(&*programme)),
+        seeds = [b"project", owner.key().as_ref(), site.project_id.as_bytes()],
         bump
     )]
-    pub monitoring_data: Account<'info, MonitoringData>,
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectIdIndex::LEN,
+        seeds = [b"project_id", site.project_id.as_bytes()],
+        bump
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
 
     #[account(
         mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        seeds = [b"registry_v3"],
+        bump = registry.bump
     )]
-    pub project: Account<'info, Project>,
+    pub registry: Account<'info, GlobalRegistry>,
 
     #[account(mut)]
-    pub data_provider: Signer<'info>,
+    pub owner: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(project_id: String)]
-pub struct CreateMarketplaceListing<'info> {
+#[instruction(methodology_data: MethodologyData)]
+pub struct RegisterMethodology<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
     #[account(
         init,
-        payer = seller,
-        space = 8 + 1000,
-        seeds = [b"listing", project_id.as_bytes(), seller.key().as_ref()],
+        payer = authority,
+        space = Methodology::init_space(serialized_len(&methodology_data)),
+        seeds = [
+            b"methodology",
+            methodology_data.methodology_id.as_bytes(),
+            methodology_data.methodology_version.as_bytes()
+        ],
         bump
     )]
-    pub listing: Account<'info, CarbonCreditListing>,
+    pub methodology: Account<'info, Methodology>,
 
     #[account(
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        seeds = [b"registry_v3"],
+        bump = registry.bump
     )]
-    pub project: Account<'info, Project>,
+    pub registry: Account<'info, GlobalRegistry>,
 
+    /// Approved admin proposal, required while multisig mode is enabled
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(project_id: String, reporting_period_end: i64)]
-pub struct GenerateImpactReport<'info> {
+pub struct DeprecateMethodology<'info> {
     #[account(
-        init,
-        payer = report_generator,
-        space = 8 + 1500,
-        seeds = [b"impact_report", project_id.as_bytes(), &reporting_period_end.to_le_bytes()],
-        bump
+        mut,
+        seeds = [
+            b"methodology",
+            methodology.methodology_id.as_bytes(),
+            methodology.methodology_version.as_bytes()
+        ],
+        bump = methodology.bump
     )]
-    pub impact_report: Account<'info, ImpactReport>,
+    pub methodology: Account<'info, Methodology>,
 
     #[account(
-        mut,
-        seeds = [b"project", project.owner.as_ref(), project.project_id.as_bytes()],
-        bump = project.bump
+        seeds = [b"registry_v3"],
+        bump = registry.bump
     )]
-    pub project: Account<'info, Project>,
+    pub registry: Account<'info, GlobalRegistry>,
 
+    /// Approved admin proposal, required while multisig mode is enabled
     #[account(mut)]
-    pub report_generator: Signer<'info>,
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCarbonBaseline<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordMonitoringPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + MonitoringPeriod::LEN,
+        seeds = [
+            b"monitoring_period",
+            project.key().as_ref(),
+            &project.monitoring_period_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub monitoring_period: Account<'info, MonitoringPeriod>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub verifier_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Required when the project was registered under a methodology
+    pub methodology: Option<Account<'info, Methodology>>,
+
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(update: ProjectUpdate)]
+pub struct UpdateProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ProjectAmendment::space_for(&update),
+        seeds = [b"amendment", project.key().as_ref(), &project.amendment_count.to_le_bytes()],
+        bump
+    )]
+    pub amendment: Account<'info, ProjectAmendment>,
+
+    /// Required when a programme site changes its boundary, area or
+    /// carbon science figures the eligibility criteria cover
+    #[account(mut)]
+    pub programme: Option<Account<'info, Programme>>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(verifier_data: VerifierData)]
+pub struct RegisterVerifier<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = VerificationNode::init_space(serialized_len(&verifier_data)),
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Verifier role assignment issued to `verifier_authority`
+    pub verifier_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved AccreditVerifier proposal, required while multisig mode is
+    /// enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Multisig member executing `proposal`
+    pub executor: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransitionProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Role assignment for transitions that need one; unused when
+    /// `authority` is the owner or the registry admin
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetProjectProponents<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Approved UpdateProponents proposal, required once the project has
+    /// proponents
+    #[account(mut)]
+    pub proposal: Option<Account<'info, ProponentProposal>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProponentProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ProponentProposal::LEN,
+        seeds = [
+            b"proponent_proposal".as_ref(),
+            project.key().as_ref(),
+            &project.proponent_proposal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, ProponentProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProponentProposal<'info> {
+    #[account(
+        mut,
+        has_one = project,
+        seeds = [
+            b"proponent_proposal".as_ref(),
+            project.key().as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ProponentProposal>,
+
+    #[account(
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProponentProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [
+            b"proponent_proposal".as_ref(),
+            proposal.project.as_ref(),
+            &proposal.index.to_le_bytes(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, ProponentProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVerifierProfile<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump,
+        constraint = verifier.verifier_pubkey == verifier_authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    verified_carbon_tons: u64,
+    quality_rating: u8,
+    verification_report_cid: String,
+    verification_report_hash: [u8; 32]
+)]
+pub struct MultiPartyVerifyProject<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier", verifier_authority.key().as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, VerificationNode>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + ProjectDocument::LEN,
+        seeds = [b"document", project.key().as_ref(), &verification_report_hash],
+        bump
+    )]
+    pub report_document: Account<'info, ProjectDocument>,
+
+    pub verifier_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Required when the project was registered under a methodology
+    pub methodology: Option<Account<'info, Methodology>>,
+
+    /// Approved MultiPartyVerifyProject proposal, required while multisig
+    /// mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    /// Multisig member executing `proposal`
+    pub executor: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: String, timestamp: i64, monitoring_input: MonitoringDataInput)]
+pub struct SubmitMonitoringData<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
+    #[account(
+        init,
+        payer = data_provider,
+        space = MonitoringData::init_space(
+            serialized_len(&project_id) + serialized_len(&monitoring_input)
+        ),
+        seeds = [b"monitoring", project_id.as_bytes(), &timestamp.to_le_bytes()],
+        bump
+    )]
+    pub monitoring_data: Account<'info, MonitoringData>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"project_id", project_id.as_bytes()],
+        bump = project_index.bump,
+        constraint = project_index.project == project.key() @ ErrorCode::ProjectIdMismatch
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub data_provider_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub data_provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppendSensorReadings<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(mut)]
+    pub monitoring_data: Account<'info, MonitoringData>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub data_provider_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub data_provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: String, listing_data: MarketplaceListingData)]
+pub struct CreateMarketplaceListing<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
+    #[account(
+        init,
+        payer = seller,
+        space = CarbonCreditListing::init_space(
+            serialized_len(&project_id) + serialized_len(&listing_data)
+        ),
+        seeds = [b"listing", project_id.as_bytes(), seller.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, CarbonCreditListing>,
+
+    #[account(
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"project_id", project_id.as_bytes()],
+        bump = project_index.bump,
+        constraint = project_index.project == project.key() @ ErrorCode::ProjectIdMismatch
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub seller_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved CreateListing proposal, required once the project has
+    /// proponents
+    #[account(mut)]
+    pub proponent_proposal: Option<Account<'info, ProponentProposal>>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: String, reporting_period_end: i64, report_data: ImpactReportData)]
+pub struct GenerateImpactReport<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    // Sized from the payload here and trimmed to fit in the handler
+    #[account(
+        init,
+        payer = report_generator,
+        space = ImpactReport::init_space(
+            serialized_len(&project_id) + serialized_len(&report_data)
+        ),
+        seeds = [b"impact_report", project_id.as_bytes(), &reporting_period_end.to_le_bytes()],
+        bump
+    )]
+    pub impact_report: Account<'info, ImpactReport>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"project_id", project_id.as_bytes()],
+        bump = project_index.bump,
+        constraint = project_index.project == project.key() @ ErrorCode::ProjectIdMismatch
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub report_generator_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub report_generator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::LEN,
+        seeds = [b"role", holder.as_ref(), &[role as u8]],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Admin role assignment, required unless `authority` is the registry admin
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", role_assignment.holder.as_ref(), &[role_assignment.role as u8]],
+        bump = role_assignment.bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ManageAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(generation: u16)]
+pub struct RotateMintAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = carbon_token_mint
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub carbon_token_mint: Account<'info, Mint>,
+
+    /// CHECK: Current mint authority, checked against the registry.
+    #[account(address = registry.mint_authority)]
+    pub current_mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: PDA that becomes the new mint authority; never read.
+    #[account(
+        seeds = [b"mint_authority", &generation.to_le_bytes()],
+        bump
+    )]
+    pub new_mint_authority: UncheckedAccount<'info>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct EnableAdminMultisig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminMultisig::LEN,
+        seeds = [b"admin_multisig"],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdminMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DisableAdminMultisig<'info> {
+    #[account(
+        mut,
+        close = executor,
+        seeds = [b"admin_multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAdminProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::LEN,
+        seeds = [b"admin_proposal".as_ref(), &registry.admin_proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"admin_proposal".as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Admin role assignment, required unless `authority` is the registry admin
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    /// Approved admin proposal, required to unpause while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRegistryConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryConfig::LEN,
+        seeds = [b"registry_config"],
+        bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump,
+        has_one = carbon_token_mint
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub carbon_token_mint: Account<'info, Mint>,
+
+    /// Registry-owned account holding the non-permanence buffer; minting
+    /// needs it, so it is created together with the config
+    #[account(
+        init,
+        payer = admin,
+        token::mint = carbon_token_mint,
+        token::authority = registry,
+        seeds = [b"buffer_pool"],
+        bump
+    )]
+    pub buffer_pool: Account<'info, TokenAccount>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRegistryConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateRegistry<'info> {
+    /// CHECK: May still be in a legacy layout; decoded by the handler.
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump
+    )]
+    pub registry: UncheckedAccount<'info>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: May still be in a legacy layout; the handler checks the
+    /// discriminator before decoding.
+    #[account(mut, owner = crate::ID)]
+    pub target: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IndexExistingProject<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ProjectIdIndex::LEN,
+        seeds = [b"project_id", project.project_id.as_bytes()],
+        bump
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    #[account(
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Approved admin proposal, required while multisig mode is enabled
+    #[account(mut)]
+    pub proposal: Option<Account<'info, AdminProposal>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageProjectTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterProject<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump,
+        has_one = owner
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"project_id", project.project_id.as_bytes()],
+        bump = project_index.bump,
+        constraint = project_index.project == project.key() @ ErrorCode::ProjectIdMismatch
+    )]
+    pub project_index: Account<'info, ProjectIdIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ArchiveMonitoringData<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(mut, close = authority)]
+    pub monitoring_data: Account<'info, MonitoringData>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ArchiveImpactReport<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(mut, close = authority)]
+    pub impact_report: Account<'info, ImpactReport>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseListing<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"listing", listing.project_id.as_bytes(), listing.seller.as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, CarbonCreditListing>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Auditor role assignment, required unless `authority` is the seller
+    /// or the registry admin
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: DocumentKind, cid: String, content_hash: [u8; 32])]
+pub struct AddProjectDocument<'info> {
+    #[account(
+        seeds = [b"registry_config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        seeds = [b"project", project.creator.as_ref(), project.project_id.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = uploader,
+        space = 8 + ProjectDocument::LEN,
+        seeds = [b"document", project.key().as_ref(), &content_hash],
+        bump
+    )]
+    pub document: Account<'info, ProjectDocument>,
+
+    /// Previous version of this document, if any
+    #[account(mut)]
+    pub supersedes: Option<Account<'info, ProjectDocument>>,
+
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    /// Verifier role assignment, required for verification reports
+    pub uploader_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(mut)]
+    pub uploader: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{require_not_paused, validate_cid},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::ProjectDocumentAdded};
+
+/// Attaches a document to a project. Project owners upload their own
+/// evidence; verification reports can only come from verifiers.
+pub fn add_project_document(
+    ctx: Context<AddProjectDocument>,
+    kind: DocumentKind,
+    cid: String,
+    content_hash: [u8; 32],
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    if kind == DocumentKind::VerificationReport {
+        require!(
+            has_permission(
+                &ctx.accounts.uploader,
+                Role::Verifier,
+                &ctx.accounts.registry,
+                ctx.accounts.uploader_role.as_deref(),
+            ),
+            ErrorCode::MissingRole
+        );
+    } else {
+        require_keys_eq!(
+            ctx.accounts.uploader.key(),
+            ctx.accounts.project.owner,
+            ErrorCode::NotProjectOwner
+        );
+    }
+    validate_cid(&cid, ctx.accounts.config.params.max_cid_len)?;
+
+    record_document(
+        &mut ctx.accounts.document,
+        ctx.accounts.project.key(),
+        kind,
+        cid,
+        content_hash,
+        ctx.accounts.uploader.key(),
+        ctx.accounts.supersedes.as_mut(),
+        ctx.bumps.document,
+    )
+}
+
+/// Fills a freshly initialised document and links it to the version it
+/// replaces. Shared with verification, which files its report this way.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn record_document(
+    document: &mut Account<ProjectDocument>,
+    project: Pubkey,
+    kind: DocumentKind,
+    cid: String,
+    content_hash: [u8; 32],
+    uploader: Pubkey,
+    supersedes: Option<&mut Account<ProjectDocument>>,
+    bump: u8,
+) -> Result<()> {
+    require!(content_hash != [0u8; 32], ErrorCode::InvalidContentHash);
+
+    let supersedes = match supersedes {
+        Some(previous) => {
+            require_keys_eq!(previous.project, project, ErrorCode::DocumentProjectMismatch);
+            require!(previous.kind == kind, ErrorCode::DocumentKindMismatch);
+            require!(previous.superseded_by.is_none(), ErrorCode::DocumentAlreadySuperseded);
+            previous.superseded_by = Some(document.key());
+            Some(previous.key())
+        }
+        None => None,
+    };
+
+    document.project = project;
+    document.kind = kind;
+    document.cid = cid;
+    document.content_hash = content_hash;
+    document.uploader = uploader;
+    document.uploaded_at = Clock::get()?.unix_timestamp;
+    document.supersedes = supersedes;
+    document.superseded_by = None;
+    document.bump = bump;
+
+    emit!(ProjectDocumentAdded {
+        project,
+        document: document.key(),
+        kind,
+        cid: document.cid.clone(),
+        content_hash,
+        uploader,
+        supersedes,
+    });
+    msg!("Document {:?} added: {}", kind, document.cid);
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ pub enum ErrorCode {
     ProjectAlreadyProcessed,
     #[msg("Verifier is not active")]
     VerifierNotActive,
-    #[msg("Invalid quality rating (must be 1-5)")]
+    #[msg("Quality rating is outside the configured range")]
     InvalidQualityRating,
     #[msg("Exceeds available quantity")]
     ExceedsAvailableQuantity,
@@ -24,4 +24,174 @@ pub enum ErrorCode {
     ComplianceValidationFailed,
     #[msg("Not enough credits to trade.")]
     InsufficientCredits,
+    #[msg("Signer is not the registry admin")]
+    Unauthorized,
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+    #[msg("No admin transfer is pending")]
+    NoPendingAdminTransfer,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("Mint authority generation must increase")]
+    StaleMintAuthorityGeneration,
+    #[msg("Multisig members must be unique, at most 10, with 1 <= threshold <= members")]
+    InvalidMultisigConfig,
+    #[msg("Admin multisig is already enabled")]
+    MultisigAlreadyEnabled,
+    #[msg("Admin multisig is not enabled")]
+    MultisigNotEnabled,
+    #[msg("Signer is not an admin multisig member")]
+    NotMultisigMember,
+    #[msg("This action requires an approved admin proposal")]
+    MultisigApprovalRequired,
+    #[msg("Proposal has not reached its approval threshold")]
+    InsufficientApprovals,
+    #[msg("Proposal does not authorize this action")]
+    ProposalActionMismatch,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal was created under a previous multisig configuration")]
+    StaleProposal,
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Project registration is paused")]
+    RegistrationPaused,
+    #[msg("Verification is paused")]
+    VerificationPaused,
+    #[msg("Credit minting is paused")]
+    MintingPaused,
+    #[msg("Credit transfers and retirements are paused")]
+    TransfersPaused,
+    #[msg("Marketplace is paused")]
+    MarketplacePaused,
+    #[msg("Invalid registry configuration")]
+    InvalidConfig,
+    #[msg("Input exceeds the configured maximum length")]
+    InputTooLong,
+    #[msg("Account is not owned by this program or has the wrong type")]
+    InvalidMigrationTarget,
+    #[msg("Account is already on the current layout version")]
+    AccountAlreadyMigrated,
+    #[msg("Account data does not match any known layout version")]
+    UnsupportedAccountVersion,
+    #[msg("Project does not match the supplied project ID")]
+    ProjectIdMismatch,
+    #[msg("Project update does not change any field")]
+    EmptyProjectUpdate,
+    #[msg("Project can no longer be edited in its current status")]
+    ProjectNotEditable,
+    #[msg("Signer is not the project owner")]
+    NotProjectOwner,
+    #[msg("No project ownership transfer is pending")]
+    NoPendingProjectTransfer,
+    #[msg("Signer is not the pending project owner")]
+    NotPendingProjectOwner,
+    #[msg("Project has issued credits and cannot be closed")]
+    ProjectHasCredits,
+    #[msg("Listing still offers credits and has not expired")]
+    ListingStillActive,
+    #[msg("Document content hash must not be empty")]
+    InvalidContentHash,
+    #[msg("Superseded document belongs to a different project")]
+    DocumentProjectMismatch,
+    #[msg("Superseded document is of a different kind")]
+    DocumentKindMismatch,
+    #[msg("Document has already been superseded")]
+    DocumentAlreadySuperseded,
+    #[msg("Invalid IPFS content identifier")]
+    InvalidContentId,
+    #[msg("Latitude or longitude out of range")]
+    InvalidCoordinates,
+    #[msg("Project boundary must be a closed ring within the vertex limits")]
+    InvalidPolygon,
+    #[msg("Project boundary intersects itself")]
+    SelfIntersectingPolygon,
+    #[msg("Country code is not an ISO 3166-1 alpha-2 code")]
+    InvalidCountryCode,
+    #[msg("Declared area does not match the project boundary")]
+    AreaMismatch,
+    #[msg("Project boundary overlaps an already registered project")]
+    SpatialOverlap,
+    #[msg("Project boundary spans too many spatial index cells")]
+    TooManyGeoCells,
+    #[msg("Spatial index cell accounts missing or out of order")]
+    InvalidGeoCell,
+    #[msg("Spatial index cell is full")]
+    GeoCellFull,
+    #[msg("Project listed in a spatial index cell was not provided")]
+    MissingOverlapCandidate,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Measurement is outside its valid range")]
+    ValueOutOfRange,
+    #[msg("Too many entries in a list field")]
+    TooManyEntries,
+    #[msg("Account would exceed the maximum account size")]
+    AccountTooLarge,
+    #[msg("Project status does not allow this transition")]
+    InvalidStatusTransition,
+    #[msg("Project is suspended")]
+    ProjectSuspended,
+    #[msg("Project has expired")]
+    ProjectExpired,
+    #[msg("Vintage is outside the project's crediting period")]
+    VintageOutsideCreditingPeriod,
+    #[msg("Crediting period has not ended")]
+    CreditingPeriodActive,
+    #[msg("Crediting period renewal limit reached")]
+    RenewalLimitReached,
+    #[msg("Invalid proponent list: shares must total 100% across unique keys")]
+    InvalidProponents,
+    #[msg("Signer is not a proponent of this project")]
+    NotProponent,
+    #[msg("This action needs an approved proponent proposal")]
+    ProponentApprovalRequired,
+    #[msg("Proposal belongs to a different project")]
+    ProposalProjectMismatch,
+    #[msg("Invalid programme eligibility criteria")]
+    InvalidEligibilityCriteria,
+    #[msg("Programme design has not been validated")]
+    ProgrammeNotValidated,
+    #[msg("Programme design is already validated")]
+    ProgrammeAlreadyValidated,
+    #[msg("Site does not meet the programme's eligibility criteria")]
+    SiteNotEligible,
+    #[msg("Field is set by the site's programme and cannot be changed")]
+    InheritedFromProgramme,
+    #[msg("Programme account does not match the project")]
+    ProgrammeMismatch,
+    #[msg("Invalid methodology parameters")]
+    InvalidMethodology,
+    #[msg("Methodology is deprecated")]
+    MethodologyInactive,
+    #[msg("Methodology account does not match the project")]
+    MethodologyMismatch,
+    #[msg("Project does not conform to its methodology")]
+    MethodologyNotConformant,
+    #[msg("Verified tons exceed the creditable carbon the project's measurements support")]
+    ExceedsCreditableCarbon,
+    #[msg("Projects with site data need a carbon baseline before verification")]
+    BaselineRequired,
+    #[msg("Carbon baseline is locked")]
+    BaselineLocked,
+    #[msg("Carbon baseline has not been locked")]
+    BaselineNotLocked,
+    #[msg("Monitoring period must start after the last one and end by now")]
+    InvalidMonitoringPeriod,
+    #[msg("Mint exceeds the net removals recorded against the baseline")]
+    ExceedsNetRemovals,
+    #[msg("Buffer rate must be at least 0% and below 100%")]
+    InvalidBufferRate,
+    #[msg("Reversal must report a loss within the project area")]
+    InvalidReversal,
+    #[msg("Project has an outstanding reversal liability")]
+    ReversalLiabilityOutstanding,
+    #[msg("Compensation exceeds the outstanding reversal liability")]
+    ExceedsReversalLiability,
+    #[msg("tokens_per_ton cannot change once credits have been issued")]
+    TokensPerTonFrozen,
+    #[msg("Project has not issued any credits")]
+    NoCreditsIssued,
+    #[msg("mint_credits is disabled; use mint_verified_credits")]
+    LegacyMintDisabled,
 }
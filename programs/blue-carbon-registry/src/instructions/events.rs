@@ -0,0 +1,287 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::fixed::*;
+
+#[event]
+pub struct AdminTransferProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferCancelled {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
+#[event]
+pub struct MintAuthorityRotated {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub generation: u16,
+}
+
+#[event]
+pub struct AdminMultisigUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub proposal: Pubkey,
+}
+
+#[event]
+pub struct ProponentsUpdated {
+    pub project: Pubkey,
+    pub proponents: Vec<Proponent>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ProponentProposalCreated {
+    pub proposal: Pubkey,
+    pub project: Pubkey,
+    pub proposer: Pubkey,
+    pub action: ProponentAction,
+}
+
+#[event]
+pub struct ProponentProposalApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProponentProposalExecuted {
+    pub proposal: Pubkey,
+    pub project: Pubkey,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct ProponentProposalCancelled {
+    pub proposal: Pubkey,
+}
+
+// Per-proponent entitlement to newly issued credits, for off-chain
+// distribution of credits and sale revenue
+#[event]
+pub struct CreditsAllocated {
+    pub project: Pubkey,
+    pub vintage_year: u16,
+    pub amount: u64,
+    pub allocations: Vec<ShareAllocation>,
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub subsystem: Subsystem,
+    pub paused: bool,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RegistryConfigUpdated {
+    pub params: ProtocolParams,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProjectAmended {
+    pub project: Pubkey,
+    pub amendment: Pubkey,
+    pub index: u32,
+    pub editor: Pubkey,
+    pub carbon_science_changed: bool,
+    pub previous_status: VerificationStatus,
+    pub new_status: VerificationStatus,
+}
+
+#[event]
+pub struct ProjectTransferProposed {
+    pub project: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectTransferAccepted {
+    pub project: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectTransferCancelled {
+    pub project: Pubkey,
+    pub owner: Pubkey,
+    pub cancelled_owner: Pubkey,
+}
+
+#[event]
+pub struct ProjectStatusChanged {
+    pub project: Pubkey,
+    pub transition: ProjectTransition,
+    pub previous_status: VerificationStatus,
+    pub new_status: VerificationStatus,
+    pub authority: Pubkey,
+    pub reason_cid: Option<String>,
+}
+
+#[event]
+pub struct ProgrammeRegistered {
+    pub programme: Pubkey,
+    pub programme_id: String,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ProgrammeValidated {
+    pub programme: Pubkey,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct ProgrammeSiteAdded {
+    pub programme: Pubkey,
+    pub project: Pubkey,
+    pub project_id: String,
+    pub site_index: u32,
+    pub area_hectares: Hectares,
+}
+
+#[event]
+pub struct ProjectDeregistered {
+    pub project: Pubkey,
+    pub project_id: String,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct RecordArchived {
+    pub record: Pubkey,
+    pub project_id: String,
+    pub archive_cid: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProjectDocumentAdded {
+    pub project: Pubkey,
+    pub document: Pubkey,
+    pub kind: DocumentKind,
+    pub cid: String,
+    pub content_hash: [u8; 32],
+    pub uploader: Pubkey,
+    pub supersedes: Option<Pubkey>,
+}
+
+#[event]
+pub struct GeoCellsClaimed {
+    pub project: Pubkey,
+    pub cells: Vec<String>,
+}
+
+#[event]
+pub struct MethodologyRegistered {
+    pub methodology: Pubkey,
+    pub methodology_id: String,
+    pub methodology_version: String,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MethodologyDeprecated {
+    pub methodology: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct CarbonBaselineSet {
+    pub project: Pubkey,
+    pub carbon_stock: Tonnes,
+    pub emissions_annual: Tonnes,
+    pub evidence_cid: String,
+}
+
+#[event]
+pub struct CarbonBaselineLocked {
+    pub project: Pubkey,
+    pub carbon_stock: Tonnes,
+    pub emissions_annual: Tonnes,
+}
+
+#[event]
+pub struct MonitoringPeriodRecorded {
+    pub project: Pubkey,
+    pub monitoring_period: Pubkey,
+    pub index: u32,
+    pub period_start: i64,
+    pub period_end: i64,
+    pub net_removals: Tonnes,
+    pub total_net_removals: Tonnes,
+}
+
+#[event]
+pub struct BufferRateSet {
+    pub project: Pubkey,
+    pub rate: Option<BasisPoints>,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct BufferContributed {
+    pub project: Pubkey,
+    pub amount: u64,
+    pub rate: BasisPoints,
+    pub buffer_balance: u64,
+}
+
+#[event]
+pub struct ReversalReported {
+    pub project: Pubkey,
+    pub reversal: Pubkey,
+    pub index: u32,
+    pub co2e_lost: Tonnes,
+    pub intentional: bool,
+    pub buffer_cancelled: u64,
+    pub liability: u64,
+    pub reversal_liability: u64,
+}
+
+#[event]
+pub struct ReversalCompensated {
+    pub project: Pubkey,
+    pub amount: u64,
+    pub reversal_liability: u64,
+}
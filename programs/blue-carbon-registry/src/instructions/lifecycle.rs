@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{require_not_paused, validate_cid},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::ProjectStatusChanged};
+
+/// Owner hands a pending project to the verifiers.
+pub fn submit_for_review(ctx: Context<TransitionProject>) -> Result<()> {
+    transition_project(ctx, ProjectTransition::SubmitForReview, None)
+}
+
+/// Verifier turns a project down; `reason_cid` points at the findings.
+pub fn reject_project(ctx: Context<TransitionProject>, reason_cid: String) -> Result<()> {
+    transition_project(ctx, ProjectTransition::Reject, Some(reason_cid))
+}
+
+/// Owner sends a rejected project back for review after addressing the
+/// findings, usually with update_project first.
+pub fn resubmit_project(ctx: Context<TransitionProject>) -> Result<()> {
+    transition_project(ctx, ProjectTransition::Resubmit, None)
+}
+
+/// Verifier moves a verified project into its monitoring phase.
+pub fn enter_monitoring(ctx: Context<TransitionProject>) -> Result<()> {
+    transition_project(ctx, ProjectTransition::EnterMonitoring, None)
+}
+
+/// Auditor halts issuance for a verified project pending investigation.
+pub fn suspend_project(ctx: Context<TransitionProject>, reason_cid: String) -> Result<()> {
+    transition_project(ctx, ProjectTransition::Suspend, Some(reason_cid))
+}
+
+/// Admin lifts a suspension; the project resumes as Verified.
+pub fn reinstate_project(ctx: Context<TransitionProject>) -> Result<()> {
+    transition_project(ctx, ProjectTransition::Reinstate, None)
+}
+
+/// Auditor retires a project at the end of its life. Expired projects can
+/// no longer be edited, minted against or listed.
+pub fn expire_project(ctx: Context<TransitionProject>) -> Result<()> {
+    transition_project(ctx, ProjectTransition::Expire, None)
+}
+
+/// Permissionless crank: expires a project whose crediting period has
+/// ended, so nothing more can be minted or listed for it.
+pub fn expire_crediting_period(ctx: Context<TransitionProject>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.project.crediting_period_ended(now),
+        ErrorCode::CreditingPeriodActive
+    );
+    transition_project(ctx, ProjectTransition::EndCreditingPeriod, None)
+}
+
+/// Owner applies for a further crediting period once the current one has
+/// ended. The project goes back to UnderReview and the new period starts
+/// when it is verified again.
+pub fn renew_crediting_period(ctx: Context<TransitionProject>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let project = &ctx.accounts.project;
+    require!(project.crediting_period_ended(now), ErrorCode::CreditingPeriodActive);
+    require!(
+        project.crediting_period_renewals < ctx.accounts.config.params.max_crediting_renewals,
+        ErrorCode::RenewalLimitReached
+    );
+
+    ctx.accounts.project.crediting_period_renewals += 1;
+    transition_project(ctx, ProjectTransition::Renew, None)
+}
+
+/// Opens a crediting period of `years` from now, unless the project is
+/// being re-verified inside a period that is still running.
+pub(crate) fn start_crediting_period(project: &mut Project, years: u16) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if project.has_crediting_period() && !project.crediting_period_ended(now) {
+        return Ok(());
+    }
+
+    project.crediting_period_start = now;
+    project.crediting_period_end = now
+        .checked_add(years as i64 * Project::SECONDS_PER_YEAR)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!(
+        "Crediting period for {}: {} to {}",
+        project.project_id,
+        project.crediting_period_start,
+        project.crediting_period_end
+    );
+
+    Ok(())
+}
+
+fn transition_project(
+    ctx: Context<TransitionProject>,
+    transition: ProjectTransition,
+    reason_cid: Option<String>,
+) -> Result<()> {
+    if let Some(cid) = &reason_cid {
+        validate_cid(cid, ctx.accounts.config.params.max_cid_len)?;
+    }
+    authorize_transition(
+        transition,
+        &ctx.accounts.authority,
+        &ctx.accounts.project,
+        &ctx.accounts.registry,
+        ctx.accounts.authority_role.as_deref(),
+    )?;
+    apply_transition(
+        &mut ctx.accounts.project,
+        transition,
+        ctx.accounts.authority.key(),
+        reason_cid,
+    )
+}
+
+/// Checks that `authority` may perform `transition` on `project` and that
+/// the subsystem it belongs to is not paused.
+pub(crate) fn authorize_transition(
+    transition: ProjectTransition,
+    authority: &Signer,
+    project: &Project,
+    registry: &GlobalRegistry,
+    role_assignment: Option<&RoleAssignment>,
+) -> Result<()> {
+    if let Some(subsystem) = transition.subsystem() {
+        require_not_paused(registry, subsystem)?;
+    }
+    match transition.authority() {
+        TransitionAuthority::Owner => {
+            require_keys_eq!(authority.key(), project.owner, ErrorCode::Unauthorized);
+        }
+        TransitionAuthority::Role(role) => {
+            require!(
+                has_permission(authority, role, registry, role_assignment),
+                ErrorCode::MissingRole
+            );
+        }
+        TransitionAuthority::Anyone => {}
+    }
+    Ok(())
+}
+
+/// Moves `project` along `transition` if the transition table allows it
+/// from the current status, and emits ProjectStatusChanged. Callers are
+/// responsible for authorization.
+pub(crate) fn apply_transition(
+    project: &mut Account<Project>,
+    transition: ProjectTransition,
+    authority: Pubkey,
+    reason_cid: Option<String>,
+) -> Result<()> {
+    let previous_status = project.verification_status.clone();
+    let new_status = transition
+        .next(&previous_status)
+        .ok_or(ErrorCode::InvalidStatusTransition)?;
+    project.verification_status = new_status.clone();
+
+    msg!(
+        "Project {}: {:?} -> {:?} ({:?})",
+        project.project_id,
+        previous_status,
+        new_status,
+        transition
+    );
+    emit!(ProjectStatusChanged {
+        project: project.key(),
+        transition,
+        previous_status,
+        new_status,
+        authority,
+        reason_cid,
+    });
+
+    Ok(())
+}
@@ -1,26 +1,58 @@
 use anchor_lang::prelude::*;
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::auth_utils::{
+    access::{execute_proponent_proposal, has_permission},
+    validation::{
+        require_issuing, require_not_paused, require_vintage_creditable, validate_len,
+        validate_max_entries,
+    },
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, resize::fit_account_space};
 
 pub fn create_marketplace_listing(
     ctx: Context<CreateMarketplaceListing>,
     _project_id: String,
     listing_data: MarketplaceListingData,
 ) -> Result<()> {
-    let listing = &mut ctx.accounts.listing;
-    let project = &ctx.accounts.project;
+    require_not_paused(&ctx.accounts.registry, Subsystem::Marketplace)?;
 
     require!(
-        project.verification_status == VerificationStatus::Verified,
-        ErrorCode::ProjectNotVerified
+        has_permission(
+            &ctx.accounts.seller,
+            Role::MarketplaceOperator,
+            &ctx.accounts.registry,
+            ctx.accounts.seller_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
     );
 
+    let params = &ctx.accounts.config.params;
+    validate_listing_data(&listing_data, params)?;
+
+    let listing = &mut ctx.accounts.listing;
+    let project = &ctx.accounts.project;
+
+    require_issuing(&project.verification_status)?;
+    require_vintage_creditable(project, listing_data.vintage_year)?;
+    execute_proponent_proposal(
+        &ctx.accounts.seller,
+        project,
+        ctx.accounts.proponent_proposal.as_mut(),
+        &ProponentAction::CreateListing {
+            seller: ctx.accounts.seller.key(),
+            vintage_year: listing_data.vintage_year,
+            quantity_available: listing_data.quantity_available,
+            price_per_ton: listing_data.price_per_ton,
+        },
+    )?;
+
     require!(
         listing_data.quantity_available <= project.available_quantity,
         ErrorCode::ExceedsAvailableQuantity
     );
 
-    listing.project_id = listing_data.project_id.clone();
+    listing.version = CarbonCreditListing::VERSION;
+    listing.project_id = project.project_id.clone();
     listing.seller = ctx.accounts.seller.key();
     listing.vintage_year = listing_data.vintage_year;
     listing.quantity_available = listing_data.quantity_available;
@@ -35,5 +67,24 @@ pub fn create_marketplace_listing(
     msg!("Marketplace listing created for project: {}", listing.project_id);
     msg!("Quantity: {} tons at {} per ton", listing.quantity_available, listing.price_per_ton);
 
+    fit_account_space(
+        &ctx.accounts.listing,
+        &ctx.accounts.seller,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Length and count limits for a new listing.
+pub(crate) fn validate_listing_data(
+    listing_data: &MarketplaceListingData,
+    params: &ProtocolParams,
+) -> Result<()> {
+    validate_max_entries(
+        listing_data.certification_standards.len(),
+        CarbonCreditListing::MAX_CERTIFICATIONS,
+    )?;
+    for standard in &listing_data.certification_standards {
+        validate_len(standard, params.max_name_len)?;
+    }
     Ok(())
 }
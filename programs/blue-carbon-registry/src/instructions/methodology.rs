@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::models::*;
+use crate::auth_utils::{
+    access::execute_admin_proposal,
+    validation::{validate_authority, validate_methodology_conformance, validate_methodology_data},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*, resize::fit_account_space};
+
+/// Publishes a methodology projects can register under. Admin key in
+/// single mode; in multisig mode an approved RegisterMethodology proposal
+/// carrying the hash of `methodology_data`.
+pub fn register_methodology(
+    ctx: Context<RegisterMethodology>,
+    methodology_data: MethodologyData,
+) -> Result<()> {
+    validate_methodology_data(&methodology_data, ctx.accounts.config.params.max_cid_len)?;
+
+    let accounts = ctx.accounts;
+    if accounts.registry.multisig_enabled {
+        let data_hash = hash(&methodology_data.try_to_vec()?).to_bytes();
+        execute_admin_proposal(
+            &accounts.authority,
+            &accounts.registry,
+            accounts.proposal.as_mut(),
+            &AdminAction::RegisterMethodology {
+                methodology: accounts.methodology.key(),
+                data_hash,
+            },
+        )?;
+    } else {
+        validate_authority(&accounts.authority, &accounts.registry)?;
+    }
+
+    let methodology = &mut accounts.methodology;
+    methodology.version = Methodology::VERSION;
+    methodology.methodology_id = methodology_data.methodology_id;
+    methodology.methodology_version = methodology_data.methodology_version;
+    methodology.document_cid = methodology_data.document_cid;
+    methodology.eligible_ecosystems = methodology_data.eligible_ecosystems;
+    methodology.emission_factors = methodology_data.emission_factors;
+    methodology.monitoring_interval_days = methodology_data.monitoring_interval_days;
+    methodology.buffer_percentage = methodology_data.buffer_percentage;
+    methodology.max_uncertainty = methodology_data.max_uncertainty;
+    methodology.uncertainty_allowance = methodology_data.uncertainty_allowance;
+    methodology.max_leakage = methodology_data.max_leakage;
+    methodology.active = true;
+    methodology.registered_at = Clock::get()?.unix_timestamp;
+    methodology.bump = ctx.bumps.methodology;
+
+    emit!(MethodologyRegistered {
+        methodology: methodology.key(),
+        methodology_id: methodology.methodology_id.clone(),
+        methodology_version: methodology.methodology_version.clone(),
+        authority: accounts.authority.key(),
+    });
+    msg!(
+        "Methodology registered: {} v{}",
+        methodology.methodology_id,
+        methodology.methodology_version
+    );
+
+    fit_account_space(
+        &accounts.methodology,
+        &accounts.authority,
+        &accounts.system_program,
+    )
+}
+
+/// Closes a methodology to new projects. Projects already registered under
+/// it keep it and are still verified against it.
+pub fn deprecate_methodology(ctx: Context<DeprecateMethodology>) -> Result<()> {
+    let accounts = ctx.accounts;
+    if accounts.registry.multisig_enabled {
+        execute_admin_proposal(
+            &accounts.authority,
+            &accounts.registry,
+            accounts.proposal.as_mut(),
+            &AdminAction::DeprecateMethodology {
+                methodology: accounts.methodology.key(),
+            },
+        )?;
+    } else {
+        validate_authority(&accounts.authority, &accounts.registry)?;
+    }
+
+    let methodology = &mut accounts.methodology;
+    require!(methodology.active, ErrorCode::MethodologyInactive);
+    methodology.active = false;
+
+    emit!(MethodologyDeprecated {
+        methodology: methodology.key(),
+        authority: accounts.authority.key(),
+    });
+    msg!(
+        "Methodology deprecated: {} v{}",
+        methodology.methodology_id,
+        methodology.methodology_version
+    );
+
+    Ok(())
+}
+
+/// Verification-time check for projects registered under a methodology:
+/// the matching account must be supplied and the project must still
+/// conform to it after any amendments. Returns the methodology to apply,
+/// which is `None` for projects without one whatever was passed.
+pub(crate) fn check_project_methodology<'a>(
+    project: &Project,
+    methodology: Option<&'a Account<Methodology>>,
+) -> Result<Option<&'a Methodology>> {
+    let expected = match project.methodology {
+        Some(key) => key,
+        None => return Ok(None),
+    };
+    let methodology = methodology.ok_or(ErrorCode::MethodologyMismatch)?;
+    require_keys_eq!(methodology.key(), expected, ErrorCode::MethodologyMismatch);
+
+    validate_methodology_conformance(
+        methodology,
+        &project.vcs_methodology,
+        &project.ecosystem_type,
+        project.uncertainty_percentage,
+        project.leakage_assessment,
+    )?;
+    Ok(Some(methodology))
+}
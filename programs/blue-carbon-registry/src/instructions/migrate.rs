@@ -0,0 +1,628 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::models::*;
+use crate::legacy::*;
+use crate::auth_utils::access::{authorize_admin_action, execute_admin_proposal};
+use crate::instructions::{contexts::*, errors::ErrorCode, resize::resize_account};
+
+/// Upgrades the registry PDA in place. The registry cannot be loaded as an
+/// `Account` until this has run, so the admin and multisig mode are read
+/// from the legacy data.
+pub fn migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+    let target = ctx.accounts.registry.to_account_info();
+    let migrated: GlobalRegistry = decode_for_migration::<GlobalRegistry>(&target, |_| 8 + GlobalRegistry::LEN)?;
+    if migrated.multisig_enabled {
+        execute_admin_proposal(
+            &ctx.accounts.admin,
+            &migrated,
+            ctx.accounts.proposal.as_mut(),
+            &AdminAction::MigrateAccount { target: target.key() },
+        )?;
+    } else {
+        require_keys_eq!(ctx.accounts.admin.key(), migrated.admin, ErrorCode::Unauthorized);
+    }
+
+    write_migrated(
+        &target,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        &migrated,
+        8 + GlobalRegistry::LEN,
+    )?;
+
+    msg!("Registry migrated to layout version {}", GlobalRegistry::VERSION);
+
+    Ok(())
+}
+
+pub fn migrate_project(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<Project>(ctx)
+}
+
+pub fn migrate_verifier(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<VerificationNode>(ctx)
+}
+
+pub fn migrate_monitoring_data(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<MonitoringData>(ctx)
+}
+
+pub fn migrate_listing(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<CarbonCreditListing>(ctx)
+}
+
+pub fn migrate_impact_report(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<ImpactReport>(ctx)
+}
+
+fn migrate_account<T>(ctx: Context<MigrateAccount>) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Versioned + FromLegacy + DynamicSpace,
+{
+    let target = ctx.accounts.target.to_account_info();
+    authorize_admin_action(
+        &ctx.accounts.admin,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::MigrateAccount { target: target.key() },
+    )?;
+
+    let migrated = decode_for_migration::<T>(&target, T::space)?;
+
+    write_migrated(
+        &target,
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        &migrated,
+        migrated.space(),
+    )?;
+
+    msg!("Account {} migrated to layout version {}", target.key(), T::VERSION);
+
+    Ok(())
+}
+
+/// Decodes `target` from its baseline layout, told apart from the current
+/// one by its discriminator. Current accounts are only rewritten when they
+/// are smaller than `min_space`, i.e. allocated before a length budget grew.
+fn decode_for_migration<T>(target: &AccountInfo, min_space: impl Fn(&T) -> usize) -> Result<T>
+where
+    T: AccountDeserialize + Discriminator + Versioned + FromLegacy,
+{
+    let data = target.try_borrow_data()?;
+    require!(data.len() >= 8, ErrorCode::InvalidMigrationTarget);
+
+    if data[..8] == T::LEGACY_DISCRIMINATOR {
+        return T::from_legacy(&data[8..]).ok_or_else(|| error!(ErrorCode::UnsupportedAccountVersion));
+    }
+    require!(&data[..8] == T::DISCRIMINATOR, ErrorCode::InvalidMigrationTarget);
+    require!(data.get(8) == Some(&T::VERSION), ErrorCode::UnsupportedAccountVersion);
+
+    let current = T::try_deserialize(&mut &data[..])?;
+    require!(data.len() < min_space(&current), ErrorCode::AccountAlreadyMigrated);
+    Ok(current)
+}
+
+/// Rewrites `target` with `account`, growing it (and topping up rent from
+/// `payer`) when the new layout no longer fits.
+fn write_migrated<'info, T: AccountSerialize>(
+    target: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    account: &T,
+    min_space: usize,
+) -> Result<()> {
+    let mut serialized = Vec::new();
+    account.try_serialize(&mut serialized)?;
+
+    let space = serialized.len().max(min_space).max(target.data_len());
+    resize_account(target, payer, system_program, space)?;
+
+    let mut data = target.try_borrow_mut_data()?;
+    data.fill(0);
+    data[..serialized.len()].copy_from_slice(&serialized);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixed::*;
+
+    fn key(seed: u8) -> Pubkey {
+        Pubkey::new_from_array([seed; 32])
+    }
+
+    // A legacy account: the V0 body under T's baseline discriminator
+    fn legacy_account<T: FromLegacy>(body: &impl AnchorSerialize) -> Vec<u8> {
+        let mut data = T::LEGACY_DISCRIMINATOR.to_vec();
+        body.serialize(&mut data).unwrap();
+        data
+    }
+
+    fn decode<T>(mut data: Vec<u8>, min_space: impl Fn(&T) -> usize) -> Result<T>
+    where
+        T: AccountDeserialize + Discriminator + Versioned + FromLegacy,
+    {
+        let (address, owner) = (key(0), crate::ID);
+        let mut lamports = 0;
+        let target = AccountInfo::new(
+            &address,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        decode_for_migration::<T>(&target, min_space)
+    }
+
+    // Writes `account` back as write_migrated would and migrates it again
+    fn remigrate<T>(account: &T, min_space: impl Fn(&T) -> usize + Copy) -> Result<T>
+    where
+        T: AccountSerialize + AccountDeserialize + Discriminator + Versioned + FromLegacy,
+    {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(data.len().max(min_space(account)), 0);
+        decode(data, min_space)
+    }
+
+    fn already_migrated() -> Result<()> {
+        Err(ErrorCode::AccountAlreadyMigrated.into())
+    }
+
+    fn site_v0() -> GeoLocationV0 {
+        GeoLocationV0 {
+            latitude: -8.6705,
+            longitude: 115.2126,
+            polygon_coordinates: vec![
+                [115.2126, -8.6705],
+                [115.2226, -8.6705],
+                [115.2226, -8.6605],
+            ],
+            country_code: "ID".to_string(),
+            region_name: "Bali".to_string(),
+        }
+    }
+
+    #[test]
+    fn migrates_global_registry() {
+        let legacy = GlobalRegistryV0 {
+            total_credits_issued: 5_000,
+            total_projects: 7,
+            admin: key(1),
+            mint_authority: key(2),
+            carbon_token_mint: key(3),
+            bump: 254,
+            mint_authority_bump: 253,
+        };
+        let space = |_: &GlobalRegistry| 8 + GlobalRegistry::LEN;
+        let registry =
+            decode::<GlobalRegistry>(legacy_account::<GlobalRegistry>(&legacy), space).unwrap();
+
+        assert_eq!(registry.version, GlobalRegistry::VERSION);
+        assert_eq!(registry.total_credits_issued, 5_000);
+        assert_eq!(registry.total_projects, 7);
+        assert_eq!(registry.admin, key(1));
+        assert_eq!(registry.mint_authority, key(2));
+        assert_eq!(registry.carbon_token_mint, key(3));
+        assert_eq!(registry.bump, 254);
+        assert_eq!(registry.mint_authority_bump, 253);
+        assert_eq!(registry.pending_admin, None);
+        assert_eq!(registry.mint_authority_generation, 0);
+        assert!(!registry.multisig_enabled);
+        assert_eq!(registry.multisig_nonce, 0);
+        assert_eq!(registry.admin_proposal_count, 0);
+        assert_eq!(registry.paused_subsystems, 0);
+
+        assert_eq!(remigrate(&registry, space).map(|_| ()), already_migrated());
+    }
+
+    #[test]
+    fn migrates_project() {
+        let legacy = ProjectV0 {
+            project_id: "BC-001".to_string(),
+            owner: key(1),
+            ipfs_cid: "QmYwAPJzv5CZsnAzt8auVKRQm6VLw4Dy8YQANhBBfmGjw8".to_string(),
+            carbon_tons_estimated: 1_000,
+            verification_status: VerificationStatus::Verified,
+            credits_issued: 400,
+            tokens_minted: 400,
+            bump: 252,
+            ecosystem_type: EcosystemType::Mangrove,
+            location: site_v0(),
+            area_hectares: 12.5,
+            species_composition: vec!["Rhizophora mucronata".to_string()],
+            biodiversity_index: 0.72,
+            establishment_date: 1_600_000_000,
+            above_ground_biomass: 101.25,
+            below_ground_biomass: 33.3333333,
+            soil_carbon_0_30cm: 60.0,
+            soil_carbon_30_100cm: 80.5,
+            sequestration_rate_annual: 5.25,
+            measurement_methodology: "IPCC 2013 Wetlands Supplement".to_string(),
+            uncertainty_percentage: 7.5,
+            vcs_methodology: "VM0033".to_string(),
+            additionality_proof_cid: "proof".to_string(),
+            permanence_guarantee_years: 30,
+            leakage_assessment: 12.34,
+            monitoring_plan_cid: "plan".to_string(),
+            baseline_ecosystem_health: 60.0,
+            current_ecosystem_health: 64.5,
+            species_count_baseline: 12,
+            species_count_current: 15,
+            co_benefits: vec![CoBenefit::CoastalProtection],
+            vintage_year: 2024,
+            quality_rating: 4,
+            price_per_ton: 15,
+            available_quantity: 600,
+        };
+        let project =
+            decode::<Project>(legacy_account::<Project>(&legacy), Project::space).unwrap();
+
+        assert_eq!(project.version, Project::VERSION);
+        assert_eq!(project.project_id, "BC-001");
+        assert_eq!(project.owner, key(1));
+        assert_eq!(project.creator, key(1));
+        assert_eq!(project.pending_owner, None);
+        assert_eq!(
+            project.ipfs_cid,
+            "QmYwAPJzv5CZsnAzt8auVKRQm6VLw4Dy8YQANhBBfmGjw8"
+        );
+        assert_eq!(project.carbon_tons_estimated, 1_000);
+        assert_eq!(project.verification_status, VerificationStatus::Verified);
+        assert_eq!(project.credits_issued, 400);
+        assert_eq!(project.tokens_minted, 400);
+        assert_eq!(project.bump, 252);
+        assert_eq!(project.ecosystem_type, EcosystemType::Mangrove);
+        assert_eq!(project.location.latitude, Degrees(-86_705_000));
+        assert_eq!(project.location.longitude, Degrees(1_152_126_000));
+        assert_eq!(
+            project.location.polygon_coordinates,
+            vec![
+                [Degrees(1_152_126_000), Degrees(-86_705_000)],
+                [Degrees(1_152_226_000), Degrees(-86_705_000)],
+                [Degrees(1_152_226_000), Degrees(-86_605_000)],
+            ]
+        );
+        assert_eq!(project.location.country_code, "ID");
+        assert_eq!(project.location.region_name, "Bali");
+        assert_eq!(project.area_hectares, Hectares(125_000));
+        assert_eq!(
+            project.species_composition,
+            vec!["Rhizophora mucronata".to_string()]
+        );
+        assert_eq!(project.biodiversity_index, Measure(720_000));
+        assert_eq!(project.establishment_date, 1_600_000_000);
+        assert_eq!(project.above_ground_biomass, Tonnes(101_250_000));
+        // Rounded to the nearest gram
+        assert_eq!(project.below_ground_biomass, Tonnes(33_333_333));
+        assert_eq!(project.soil_carbon_0_30cm, Tonnes(60_000_000));
+        assert_eq!(project.soil_carbon_30_100cm, Tonnes(80_500_000));
+        assert_eq!(project.sequestration_rate_annual, Tonnes(5_250_000));
+        assert_eq!(
+            project.measurement_methodology,
+            "IPCC 2013 Wetlands Supplement"
+        );
+        assert_eq!(project.uncertainty_percentage, BasisPoints(750));
+        assert_eq!(project.vcs_methodology, "VM0033");
+        assert_eq!(project.additionality_proof_cid, "proof");
+        assert_eq!(project.permanence_guarantee_years, 30);
+        assert_eq!(project.leakage_assessment, BasisPoints(1_234));
+        assert_eq!(project.monitoring_plan_cid, "plan");
+        assert_eq!(project.baseline_ecosystem_health, Measure(60_000_000));
+        assert_eq!(project.current_ecosystem_health, Measure(64_500_000));
+        assert_eq!(project.species_count_baseline, 12);
+        assert_eq!(project.species_count_current, 15);
+        assert_eq!(project.co_benefits, vec![CoBenefit::CoastalProtection]);
+        assert_eq!(project.vintage_year, 2024);
+        assert_eq!(project.quality_rating, 4);
+        assert_eq!(project.price_per_ton, 15);
+        assert_eq!(project.available_quantity, 600);
+        assert_eq!(project.amendment_count, 0);
+        // Already issuing, so credited from establishment for the default period
+        let years = ProtocolParams::default().crediting_period_years as i64;
+        assert_eq!(project.crediting_period_start, 1_600_000_000);
+        assert_eq!(
+            project.crediting_period_end,
+            1_600_000_000 + years * Project::SECONDS_PER_YEAR
+        );
+        assert_eq!(project.crediting_period_renewals, 0);
+        assert!(project.proponents.is_empty());
+        assert_eq!(project.proponent_threshold, 0);
+        assert_eq!(project.proponent_nonce, 0);
+        assert_eq!(project.proponent_proposal_count, 0);
+        assert_eq!(project.programme, None);
+        assert_eq!(project.methodology, None);
+        assert_eq!(project.baseline, None);
+        assert_eq!(project.credited_stock, Tonnes::ZERO);
+        assert_eq!(project.net_removals, Tonnes::ZERO);
+        assert_eq!(project.monitoring_period_count, 0);
+        assert_eq!(project.last_monitoring_period_end, 0);
+        assert_eq!(project.buffer_rate, None);
+        assert_eq!(project.buffer_balance, 0);
+        assert_eq!(project.reversal_count, 0);
+        assert_eq!(project.tokens_reversed, 0);
+        assert_eq!(project.reversal_liability, 0);
+
+        assert_eq!(
+            remigrate(&project, Project::space).map(|_| ()),
+            already_migrated()
+        );
+    }
+
+    #[test]
+    fn leaves_unverified_projects_without_a_crediting_period() {
+        let legacy = ProjectV0 {
+            project_id: "BC-002".to_string(),
+            owner: key(1),
+            ipfs_cid: String::new(),
+            carbon_tons_estimated: 0,
+            verification_status: VerificationStatus::Pending,
+            credits_issued: 0,
+            tokens_minted: 0,
+            bump: 255,
+            ecosystem_type: EcosystemType::Seagrass,
+            location: site_v0(),
+            area_hectares: 0.0,
+            species_composition: Vec::new(),
+            biodiversity_index: 0.0,
+            establishment_date: 1_600_000_000,
+            above_ground_biomass: 0.0,
+            below_ground_biomass: 0.0,
+            soil_carbon_0_30cm: 0.0,
+            soil_carbon_30_100cm: 0.0,
+            sequestration_rate_annual: 0.0,
+            measurement_methodology: String::new(),
+            uncertainty_percentage: 0.0,
+            vcs_methodology: String::new(),
+            additionality_proof_cid: String::new(),
+            permanence_guarantee_years: 0,
+            leakage_assessment: 0.0,
+            monitoring_plan_cid: String::new(),
+            baseline_ecosystem_health: 0.0,
+            current_ecosystem_health: 0.0,
+            species_count_baseline: 0,
+            species_count_current: 0,
+            co_benefits: Vec::new(),
+            vintage_year: 2024,
+            quality_rating: 0,
+            price_per_ton: 0,
+            available_quantity: 0,
+        };
+        let project =
+            decode::<Project>(legacy_account::<Project>(&legacy), Project::space).unwrap();
+        assert_eq!(project.crediting_period_start, 0);
+        assert_eq!(project.crediting_period_end, 0);
+
+        // A float the fixed-point type cannot hold leaves the account as is
+        let invalid = ProjectV0 {
+            area_hectares: 1e30,
+            ..legacy
+        };
+        assert_eq!(
+            decode::<Project>(legacy_account::<Project>(&invalid), Project::space).map(|_| ()),
+            Err(ErrorCode::UnsupportedAccountVersion.into())
+        );
+    }
+
+    #[test]
+    fn migrates_verification_node() {
+        let legacy = VerificationNodeV0 {
+            verifier_pubkey: key(4),
+            verifier_type: VerifierType::CertificationBody,
+            credentials: vec!["ISO 14065".to_string()],
+            reputation_score: 120,
+            verification_count: 3,
+            is_active: true,
+            registration_date: 1_650_000_000,
+            specializations: vec![EcosystemType::Mangrove, EcosystemType::SaltMarsh],
+        };
+        let node = decode::<VerificationNode>(
+            legacy_account::<VerificationNode>(&legacy),
+            VerificationNode::space,
+        )
+        .unwrap();
+
+        assert_eq!(node.version, VerificationNode::VERSION);
+        assert_eq!(node.verifier_pubkey, key(4));
+        assert_eq!(node.verifier_type, VerifierType::CertificationBody);
+        assert_eq!(node.credentials, vec!["ISO 14065".to_string()]);
+        assert_eq!(node.reputation_score, 120);
+        assert_eq!(node.verification_count, 3);
+        assert!(node.is_active);
+        assert_eq!(node.registration_date, 1_650_000_000);
+        assert_eq!(
+            node.specializations,
+            vec![EcosystemType::Mangrove, EcosystemType::SaltMarsh]
+        );
+
+        assert_eq!(
+            remigrate(&node, VerificationNode::space).map(|_| ()),
+            already_migrated()
+        );
+    }
+
+    #[test]
+    fn migrates_monitoring_data() {
+        let legacy = MonitoringDataV0 {
+            project_id: "BC-001".to_string(),
+            timestamp: 1_700_000_000,
+            satellite_imagery_cid: "imagery".to_string(),
+            ndvi_index: 0.81,
+            water_quality: WaterQualityV0 {
+                ph_level: 7.9,
+                salinity: 33.5,
+                dissolved_oxygen: 6.2,
+                turbidity: 1.75,
+                nutrients: NutrientLevelsV0 {
+                    nitrogen: 0.4,
+                    phosphorus: 0.05,
+                    potassium: 0.3,
+                },
+            },
+            temperature_data: vec![28.5, -1.25],
+            tide_data: vec![TideReadingV0 {
+                timestamp: 1_700_000_100,
+                tide_height: 1.82,
+                tide_type: "high".to_string(),
+            }],
+            iot_sensor_data: vec![SensorReadingV0 {
+                sensor_id: "S1".to_string(),
+                timestamp: 1_700_000_200,
+                co2_flux: -2.5,
+                soil_moisture: 45.5,
+                ph_level: 7.1,
+                temperature: 29.0,
+                humidity: 80.25,
+            }],
+            ecosystem_health_score: 72.5,
+        };
+        let data = decode::<MonitoringData>(
+            legacy_account::<MonitoringData>(&legacy),
+            MonitoringData::space,
+        )
+        .unwrap();
+
+        assert_eq!(data.version, MonitoringData::VERSION);
+        assert_eq!(data.project_id, "BC-001");
+        assert_eq!(data.timestamp, 1_700_000_000);
+        assert_eq!(data.satellite_imagery_cid, "imagery");
+        assert_eq!(data.ndvi_index, Measure(810_000));
+        assert_eq!(data.water_quality.ph_level, Measure(7_900_000));
+        assert_eq!(data.water_quality.salinity, Measure(33_500_000));
+        assert_eq!(data.water_quality.dissolved_oxygen, Measure(6_200_000));
+        assert_eq!(data.water_quality.turbidity, Measure(1_750_000));
+        assert_eq!(data.water_quality.nutrients.nitrogen, Measure(400_000));
+        assert_eq!(data.water_quality.nutrients.phosphorus, Measure(50_000));
+        assert_eq!(data.water_quality.nutrients.potassium, Measure(300_000));
+        assert_eq!(
+            data.temperature_data,
+            vec![Measure(28_500_000), Measure(-1_250_000)]
+        );
+        assert_eq!(data.tide_data.len(), 1);
+        assert_eq!(data.tide_data[0].timestamp, 1_700_000_100);
+        assert_eq!(data.tide_data[0].tide_height, Measure(1_820_000));
+        assert_eq!(data.tide_data[0].tide_type, "high");
+        assert_eq!(data.iot_sensor_data.len(), 1);
+        let sensor = &data.iot_sensor_data[0];
+        assert_eq!(sensor.sensor_id, "S1");
+        assert_eq!(sensor.timestamp, 1_700_000_200);
+        assert_eq!(sensor.co2_flux, Measure(-2_500_000));
+        assert_eq!(sensor.soil_moisture, BasisPoints(4_550));
+        assert_eq!(sensor.ph_level, Measure(7_100_000));
+        assert_eq!(sensor.temperature, Measure(29_000_000));
+        assert_eq!(sensor.humidity, BasisPoints(8_025));
+        assert_eq!(data.ecosystem_health_score, Measure(72_500_000));
+
+        assert_eq!(
+            remigrate(&data, MonitoringData::space).map(|_| ()),
+            already_migrated()
+        );
+    }
+
+    #[test]
+    fn migrates_listing() {
+        let legacy = CarbonCreditListingV0 {
+            project_id: "BC-001".to_string(),
+            seller: key(5),
+            vintage_year: 2024,
+            quantity_available: 250,
+            price_per_ton: 18,
+            quality_rating: 4,
+            co_benefits: vec![CoBenefit::FisheryEnhancement],
+            certification_standards: vec!["VCS".to_string()],
+            listing_date: 1_700_000_000,
+            expiry_date: 1_800_000_000,
+            is_active: true,
+        };
+        let listing = decode::<CarbonCreditListing>(
+            legacy_account::<CarbonCreditListing>(&legacy),
+            CarbonCreditListing::space,
+        )
+        .unwrap();
+
+        assert_eq!(listing.version, CarbonCreditListing::VERSION);
+        assert_eq!(listing.project_id, "BC-001");
+        assert_eq!(listing.seller, key(5));
+        assert_eq!(listing.vintage_year, 2024);
+        assert_eq!(listing.quantity_available, 250);
+        assert_eq!(listing.price_per_ton, 18);
+        assert_eq!(listing.quality_rating, 4);
+        assert_eq!(listing.co_benefits, vec![CoBenefit::FisheryEnhancement]);
+        assert_eq!(listing.certification_standards, vec!["VCS".to_string()]);
+        assert_eq!(listing.listing_date, 1_700_000_000);
+        assert_eq!(listing.expiry_date, 1_800_000_000);
+        assert!(listing.is_active);
+
+        assert_eq!(
+            remigrate(&listing, CarbonCreditListing::space).map(|_| ()),
+            already_migrated()
+        );
+    }
+
+    #[test]
+    fn migrates_impact_report() {
+        let legacy = ImpactReportV0 {
+            project_id: "BC-001".to_string(),
+            reporting_period_start: 1_700_000_000,
+            reporting_period_end: 1_731_536_000,
+            carbon_sequestered: 812.5,
+            ecosystem_health_improvement: 4.5,
+            biodiversity_increase: 25.0,
+            community_benefits: vec![CommunityBenefitV0 {
+                benefit_type: "fisheries".to_string(),
+                households_affected: 120,
+                jobs_created: 14,
+                income_increase_percentage: 12.5,
+                capacity_building_programs: 2,
+            }],
+            economic_impact: EconomicImpactV0 {
+                direct_revenue: 50_000,
+                indirect_benefits: 20_000,
+                cost_savings: 5_000,
+                roi_percentage: -3.75,
+                payback_period_years: 6.5,
+            },
+            sdg_contributions: vec![13, 14],
+            verification_report_cid: "report".to_string(),
+        };
+        let report =
+            decode::<ImpactReport>(legacy_account::<ImpactReport>(&legacy), ImpactReport::space)
+                .unwrap();
+
+        assert_eq!(report.version, ImpactReport::VERSION);
+        assert_eq!(report.project_id, "BC-001");
+        assert_eq!(report.reporting_period_start, 1_700_000_000);
+        assert_eq!(report.reporting_period_end, 1_731_536_000);
+        assert_eq!(report.carbon_sequestered, Tonnes(812_500_000));
+        assert_eq!(report.ecosystem_health_improvement, Measure(4_500_000));
+        assert_eq!(report.biodiversity_increase, BasisPoints(2_500));
+        assert_eq!(report.community_benefits.len(), 1);
+        let benefit = &report.community_benefits[0];
+        assert_eq!(benefit.benefit_type, "fisheries");
+        assert_eq!(benefit.households_affected, 120);
+        assert_eq!(benefit.jobs_created, 14);
+        assert_eq!(benefit.income_increase_percentage, BasisPoints(1_250));
+        assert_eq!(benefit.capacity_building_programs, 2);
+        assert_eq!(report.economic_impact.direct_revenue, 50_000);
+        assert_eq!(report.economic_impact.indirect_benefits, 20_000);
+        assert_eq!(report.economic_impact.cost_savings, 5_000);
+        assert_eq!(report.economic_impact.roi_percentage, BasisPoints(-375));
+        assert_eq!(
+            report.economic_impact.payback_period_years,
+            Measure(6_500_000)
+        );
+        assert_eq!(report.sdg_contributions, vec![13, 14]);
+        assert_eq!(report.verification_report_cid, "report");
+
+        assert_eq!(
+            remigrate(&report, ImpactReport::space).map(|_| ()),
+            already_migrated()
+        );
+    }
+}
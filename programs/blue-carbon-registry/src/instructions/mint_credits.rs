@@ -1,7 +1,19 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, MintTo};
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::auth_utils::{
+    access::execute_proponent_proposal,
+    validation::{
+        require_issuing, require_not_paused, require_vintage_creditable, require_within_net_removals,
+    },
+};
+use crate::instructions::{
+    buffer::buffer_share,
+    contexts::*,
+    errors::ErrorCode,
+    events::{BufferContributed, CreditsAllocated},
+    methodology::check_project_methodology,
+};
 
 pub fn initialize_registry(
     ctx: Context<InitializeRegistry>,
@@ -9,6 +21,7 @@ pub fn initialize_registry(
 ) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
     
+    registry.version = GlobalRegistry::VERSION;
     registry.total_credits_issued = 0;
     registry.total_projects = 0;
     registry.admin = ctx.accounts.admin.key();
@@ -16,6 +29,12 @@ pub fn initialize_registry(
     registry.carbon_token_mint = ctx.accounts.carbon_token_mint.key();
     registry.bump = ctx.bumps.registry;
     registry.mint_authority_bump = ctx.bumps.registry;
+    registry.pending_admin = None;
+    registry.mint_authority_generation = 0;
+    registry.multisig_enabled = false;
+    registry.multisig_nonce = 0;
+    registry.admin_proposal_count = 0;
+    registry.paused_subsystems = 0;
     
     msg!("Carbon Credit Registry initialized successfully!");
     msg!("Admin: {}", registry.admin);
@@ -26,49 +45,78 @@ pub fn initialize_registry(
 
 pub fn mint_verified_credits(
     ctx: Context<MintVerifiedCredits>, 
-    amount: u64
+    amount: u64,
+    vintage_year: u16,
 ) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Minting)?;
+
     let project = &mut ctx.accounts.project;
     
-    // Ensure project is verified
-    require!(
-        project.verification_status == VerificationStatus::Verified,
-        ErrorCode::ProjectNotVerified
-    );
+    // Ensure project is verified and issuing
+    require_issuing(&project.verification_status)?;
+    require_vintage_creditable(project, vintage_year)?;
+    require!(project.reversal_liability == 0, ErrorCode::ReversalLiabilityOutstanding);
+    execute_proponent_proposal(
+        &ctx.accounts.owner,
+        project,
+        ctx.accounts.proponent_proposal.as_mut(),
+        &ProponentAction::MintCredits {
+            amount,
+            vintage_year,
+            recipient: ctx.accounts.recipient.key(),
+        },
+    )?;
 
     // Ensure we don't exceed the verified carbon tons (1 token = 1 ton)
-    // Scale carbon_tons_estimated to match token precision (6 decimals)
-    let verified_capacity = project.carbon_tons_estimated * 10u64.pow(6);
-    require!(
-        project.tokens_minted + amount <= verified_capacity,
-        ErrorCode::ExceedsVerifiedCapacity
-    );
-
-    // Get the bump from registry without mutable borrow
-    let registry_bump = ctx.accounts.registry.bump;
-
-    // Create the context for the `mint_to` instruction of the SPL Token Program.
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.carbon_token_mint.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        authority: ctx.accounts.registry.to_account_info(),
-    };
+    // Scale carbon_tons_estimated to match token precision
+    let verified_capacity = project
+        .carbon_tons_estimated
+        .checked_mul(ctx.accounts.config.params.tokens_per_ton)
+        .ok_or(ErrorCode::ExceedsVerifiedCapacity)?;
+    let tokens_minted = project
+        .tokens_minted
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(tokens_minted <= verified_capacity, ErrorCode::ExceedsVerifiedCapacity);
+    require_within_net_removals(project, ctx.accounts.config.params.tokens_per_ton, amount)?;
+
+    // Withhold the project's buffer share; the recipient gets the rest
+    let methodology = check_project_methodology(project, ctx.accounts.methodology.as_ref())?;
+    let buffer_rate = project.effective_buffer_rate(methodology, ctx.accounts.config.params.buffer_bps);
+    let buffer_amount = buffer_share(amount, buffer_rate);
+    let recipient_amount = amount - buffer_amount;
+
+    // Issue both parts, signing as the registry's current mint authority PDA
+    let mint = ctx.accounts.carbon_token_mint.to_account_info();
+    let authority = ctx.accounts.mint_authority.to_account_info();
     let cpi_program = ctx.accounts.token_program.to_account_info();
-
-    // Seeds for the registry PDA (now the mint authority)
-    let seeds = &[b"registry_v3".as_ref(), &[registry_bump]];
-    let signer_seeds = &[&seeds[..]];
-
-    // Call the `mint_to` function to issue the tokens
-    token::mint_to(
-        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds),
-        amount,
-    )?;
-
-    // Update project and registry tracking
+    let issuance = [
+        (ctx.accounts.recipient_token_account.to_account_info(), recipient_amount),
+        (ctx.accounts.buffer_pool.to_account_info(), buffer_amount),
+    ];
+    with_mint_authority_signer(&ctx.accounts.registry, |signer_seeds| {
+        for (to, part) in issuance {
+            if part == 0 {
+                continue;
+            }
+            let cpi_accounts = MintTo {
+                mint: mint.clone(),
+                to,
+                authority: authority.clone(),
+            };
+            token::mint_to(
+                CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds),
+                part,
+            )?;
+        }
+        Ok(())
+    })?;
+
+    // Update project and registry tracking; buffered tokens count as issued
     let registry = &mut ctx.accounts.registry;
     project.credits_issued += amount;
-    project.tokens_minted += amount;
+    project.tokens_minted = tokens_minted;
+    project.buffer_balance += buffer_amount;
     registry.total_credits_issued += amount;
     
     msg!(
@@ -79,48 +127,48 @@ pub fn mint_verified_credits(
     msg!("Total tokens minted for project: {}", project.tokens_minted);
     msg!("Global total credits issued: {}", registry.total_credits_issued);
 
+    if buffer_amount > 0 {
+        emit!(BufferContributed {
+            project: project.key(),
+            amount: buffer_amount,
+            rate: buffer_rate,
+            buffer_balance: project.buffer_balance,
+        });
+        msg!("{} tokens withheld for the buffer pool", buffer_amount);
+    }
+
+    if !project.proponents.is_empty() {
+        emit!(CreditsAllocated {
+            project: project.key(),
+            vintage_year,
+            amount: recipient_amount,
+            allocations: project.proponent_split(recipient_amount),
+        });
+    }
+
     Ok(())
 }
 
-// Legacy mint_credits (deprecated)
-pub fn mint_credits(ctx: Context<MintCredits>, amount: u64) -> Result<()> {
-    let project = &mut ctx.accounts.project;
-    
-    // Basic validation
-    require!(
-        project.verification_status == VerificationStatus::Verified,
-        ErrorCode::ProjectNotVerified
-    );
-
-    // Create the context for the `mint_to` instruction
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.recipient_token_account.to_account_info(),
-        authority: ctx.accounts.mint_authority.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-
-    // PDA seeds for signing
-    let project_id_bytes = project.project_id.as_bytes();
-    let seeds = &[
-        b"project",
-        project.owner.as_ref(),
-        project_id_bytes,
-        &[project.bump],
-    ];
-    let signer = &[&seeds[..]];
-
-    // Mint the tokens
-    token::mint_to(
-        CpiContext::new_with_signer(cpi_program, cpi_accounts, signer),
-        amount,
-    )?;
-
-    // Update the project's credits_issued count
-    project.credits_issued += amount;
-    project.tokens_minted += amount;
-
-    msg!("Minted {} credits for project {}", amount, project.project_id);
+/// Runs `f` with the signer seeds of the registry's current mint authority:
+/// the registry PDA itself until the first rotation, then the
+/// [b"mint_authority", generation] PDA.
+pub(crate) fn with_mint_authority_signer<R>(
+    registry: &GlobalRegistry,
+    f: impl FnOnce(&[&[&[u8]]]) -> Result<R>,
+) -> Result<R> {
+    let bump = [registry.mint_authority_bump];
+    let generation = registry.mint_authority_generation.to_le_bytes();
+
+    if registry.mint_authority_generation == 0 {
+        f(&[&[b"registry_v3".as_ref(), &bump]])
+    } else {
+        f(&[&[b"mint_authority".as_ref(), &generation, &bump]])
+    }
+}
 
-    Ok(())
+/// Legacy mint_credits, kept in the interface but disabled: it minted the
+/// whole amount to the recipient with no buffer share and no capacity
+/// check. Callers use mint_verified_credits.
+pub fn mint_credits(_ctx: Context<MintCredits>, _amount: u64) -> Result<()> {
+    err!(ErrorCode::LegacyMintDisabled)
 }
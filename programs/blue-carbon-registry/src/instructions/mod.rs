@@ -1,20 +1,56 @@
 pub mod contexts;
 pub mod errors;
+pub mod events;
 pub mod register_project;
+pub mod update_project;
+pub mod project_transfer;
+pub mod lifecycle;
+pub mod proponents;
+pub mod programme;
+pub mod methodology;
+pub mod baseline;
+pub mod buffer;
+pub mod reversal;
+pub mod close_accounts;
+pub mod documents;
+pub mod spatial_index;
+pub mod resize;
 pub mod verify_project;
 pub mod mint_credits;
 pub mod trade_credits;
 pub mod track_impact;
 pub mod monitoring;
 pub mod marketplace;
+pub mod roles;
+pub mod admin;
+pub mod multisig;
+pub mod config;
+pub mod migrate;
 
 pub use contexts::*;
 pub use errors::*;
+pub use events::*;
 pub use register_project::*;
+pub use update_project::*;
+pub use project_transfer::*;
+pub use lifecycle::*;
+pub use proponents::*;
+pub use programme::*;
+pub use methodology::*;
+pub use baseline::*;
+pub use buffer::*;
+pub use reversal::*;
+pub use close_accounts::*;
+pub use documents::*;
 pub use verify_project::*;
 pub use mint_credits::*;
 pub use trade_credits::*;
 pub use track_impact::*;
 pub use monitoring::*;
 pub use marketplace::*;
+pub use roles::*;
+pub use admin::*;
+pub use multisig::*;
+pub use config::*;
+pub use migrate::*;
 
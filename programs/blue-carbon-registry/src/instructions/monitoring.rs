@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
 use crate::models::*;
-use crate::instructions::contexts::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{
+        validate_cid, validate_len, validate_max_entries, validate_monitoring_readings,
+        validate_sensor_reading,
+    },
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, resize::fit_account_space};
 
 pub fn submit_monitoring_data(
     ctx: Context<SubmitMonitoringData>,
@@ -8,10 +15,23 @@ pub fn submit_monitoring_data(
     _timestamp: i64,
     monitoring_data: MonitoringDataInput,
 ) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.data_provider,
+            Role::DataProvider,
+            &ctx.accounts.registry,
+            ctx.accounts.data_provider_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
+    validate_monitoring_input(&monitoring_data, &ctx.accounts.config.params)?;
+
     let monitoring = &mut ctx.accounts.monitoring_data;
     let project = &mut ctx.accounts.project;
 
-    monitoring.project_id = monitoring_data.project_id.clone();
+    monitoring.version = MonitoringData::VERSION;
+    monitoring.project_id = project.project_id.clone();
     monitoring.timestamp = Clock::get()?.unix_timestamp;
     monitoring.satellite_imagery_cid = monitoring_data.satellite_imagery_cid;
     monitoring.ndvi_index = monitoring_data.ndvi_index;
@@ -27,5 +47,64 @@ pub fn submit_monitoring_data(
     msg!("Monitoring data submitted for project: {}", monitoring.project_id);
     msg!("Ecosystem health score: {}", monitoring.ecosystem_health_score);
 
+    fit_account_space(
+        &ctx.accounts.monitoring_data,
+        &ctx.accounts.data_provider,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Content, range and length checks for a new monitoring record.
+pub(crate) fn validate_monitoring_input(input: &MonitoringDataInput, params: &ProtocolParams) -> Result<()> {
+    validate_cid(&input.satellite_imagery_cid, params.max_cid_len)?;
+    validate_monitoring_readings(input)?;
+    for reading in &input.tide_data {
+        validate_len(&reading.tide_type, params.max_name_len)?;
+    }
+    for reading in &input.iot_sensor_data {
+        validate_len(&reading.sensor_id, params.max_name_len)?;
+    }
     Ok(())
 }
+
+/// Adds IoT sensor readings that arrived after a monitoring record was
+/// submitted, growing the account to fit.
+pub fn append_sensor_readings(
+    ctx: Context<AppendSensorReadings>,
+    readings: Vec<SensorReading>,
+) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.data_provider,
+            Role::DataProvider,
+            &ctx.accounts.registry,
+            ctx.accounts.data_provider_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
+    for reading in &readings {
+        validate_sensor_reading(reading)?;
+        validate_len(&reading.sensor_id, ctx.accounts.config.params.max_name_len)?;
+    }
+
+    let monitoring = &mut ctx.accounts.monitoring_data;
+    validate_max_entries(
+        monitoring.iot_sensor_data.len() + readings.len(),
+        MonitoringData::MAX_SENSOR_READINGS,
+    )?;
+    let appended = readings.len();
+    monitoring.iot_sensor_data.extend(readings);
+
+    msg!(
+        "Appended {} sensor readings to monitoring data for project: {}",
+        appended,
+        monitoring.project_id
+    );
+
+    fit_account_space(
+        &ctx.accounts.monitoring_data,
+        &ctx.accounts.data_provider,
+        &ctx.accounts.system_program,
+    )
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::execute_admin_proposal,
+    validation::{validate_authority, validate_multisig_config},
+};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*};
+
+/// Switches the registry into multisig admin mode. Only the single admin
+/// key can do this; afterwards the multisig governs its own changes.
+pub fn enable_admin_multisig(
+    ctx: Context<EnableAdminMultisig>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    validate_authority(&ctx.accounts.admin, registry)?;
+    require!(!registry.multisig_enabled, ErrorCode::MultisigAlreadyEnabled);
+    validate_multisig_config(&members, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.members = members;
+    multisig.threshold = threshold;
+    multisig.bump = ctx.bumps.multisig;
+
+    registry.multisig_enabled = true;
+    registry.multisig_nonce += 1;
+
+    emit!(AdminMultisigUpdated {
+        members: multisig.members.clone(),
+        threshold,
+        enabled: true,
+    });
+    msg!("Admin multisig enabled: {} of {}", threshold, multisig.members.len());
+
+    Ok(())
+}
+
+pub fn update_admin_multisig(
+    ctx: Context<UpdateAdminMultisig>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_multisig_config(&members, threshold)?;
+    execute_admin_proposal(
+        &ctx.accounts.executor,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        &AdminAction::UpdateMultisig { members: members.clone(), threshold },
+    )?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.members = members;
+    multisig.threshold = threshold;
+
+    // Approvals collected under the old key set no longer count
+    ctx.accounts.registry.multisig_nonce += 1;
+
+    emit!(AdminMultisigUpdated {
+        members: multisig.members.clone(),
+        threshold,
+        enabled: true,
+    });
+    msg!("Admin multisig updated: {} of {}", threshold, multisig.members.len());
+
+    Ok(())
+}
+
+/// Returns the registry to single admin key mode and closes the multisig.
+pub fn disable_admin_multisig(ctx: Context<DisableAdminMultisig>) -> Result<()> {
+    execute_admin_proposal(
+        &ctx.accounts.executor,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        &AdminAction::DisableMultisig,
+    )?;
+
+    let registry = &mut ctx.accounts.registry;
+    registry.multisig_enabled = false;
+    registry.multisig_nonce += 1;
+
+    emit!(AdminMultisigUpdated {
+        members: Vec::new(),
+        threshold: 0,
+        enabled: false,
+    });
+    msg!("Admin multisig disabled");
+
+    Ok(())
+}
+
+pub fn create_admin_proposal(ctx: Context<CreateAdminProposal>, action: AdminAction) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let multisig = &ctx.accounts.multisig;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(registry.multisig_enabled, ErrorCode::MultisigNotEnabled);
+    require!(multisig.is_member(&proposer), ErrorCode::NotMultisigMember);
+    if let AdminAction::UpdateMultisig { members, threshold } = &action {
+        validate_multisig_config(members, *threshold)?;
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.index = registry.admin_proposal_count;
+    proposal.multisig_nonce = registry.multisig_nonce;
+    proposal.proposer = proposer;
+    proposal.action = action.clone();
+    proposal.approvals = vec![proposer];
+    proposal.threshold = multisig.threshold;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    registry.admin_proposal_count += 1;
+
+    emit!(AdminProposalCreated {
+        proposal: proposal.key(),
+        proposer,
+        action,
+    });
+    msg!("Admin proposal {} created", proposal.index);
+
+    Ok(())
+}
+
+pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = ctx.accounts.approver.key();
+
+    require!(registry.multisig_enabled, ErrorCode::MultisigNotEnabled);
+    require!(multisig.is_member(&approver), ErrorCode::NotMultisigMember);
+    require!(proposal.multisig_nonce == registry.multisig_nonce, ErrorCode::StaleProposal);
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(!proposal.approvals.contains(&approver), ErrorCode::AlreadyApproved);
+
+    proposal.approvals.push(approver);
+
+    emit!(AdminProposalApproved {
+        proposal: proposal.key(),
+        approver,
+        approvals: proposal.approvals.len() as u8,
+    });
+    msg!(
+        "Admin proposal {} approved ({}/{})",
+        proposal.index,
+        proposal.approvals.len(),
+        proposal.threshold
+    );
+
+    Ok(())
+}
+
+/// Withdraws a proposal and returns its rent to the proposer.
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+
+    emit!(AdminProposalCancelled {
+        proposal: proposal.key(),
+    });
+    msg!("Admin proposal {} cancelled", proposal.index);
+
+    Ok(())
+}
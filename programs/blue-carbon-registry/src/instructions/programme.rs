@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::fixed::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{
+        require_not_paused, validate_cid, validate_eligibility_criteria, validate_len,
+        validate_site_eligibility,
+    },
+};
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    events::*,
+    register_project::{
+        charge_registration_fee, init_blue_project, reserve_project_id, validate_project_data,
+    },
+    resize::fit_account_space,
+    spatial_index::claim_geo_cells,
+};
+
+/// Registers a grouped project design. Sites are added under it with
+/// add_programme_site once a verifier has validated the design.
+pub fn register_programme(ctx: Context<RegisterProgramme>, programme_data: ProgrammeData) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    validate_programme_data(&programme_data, &ctx.accounts.config.params)?;
+
+    let programme = &mut ctx.accounts.programme;
+    programme.version = Programme::VERSION;
+    programme.programme_id = programme_data.programme_id;
+    programme.owner = ctx.accounts.owner.key();
+    programme.creator = programme.owner;
+    programme.design_cid = programme_data.design_cid;
+    programme.ecosystem_type = programme_data.ecosystem_type;
+    programme.vcs_methodology = programme_data.vcs_methodology;
+    programme.measurement_methodology = programme_data.measurement_methodology;
+    programme.additionality_proof_cid = programme_data.additionality_proof_cid;
+    programme.monitoring_plan_cid = programme_data.monitoring_plan_cid;
+    programme.permanence_guarantee_years = programme_data.permanence_guarantee_years;
+    programme.eligibility = programme_data.eligibility;
+    programme.validated_by = None;
+    programme.validated_at = 0;
+    programme.site_count = 0;
+    programme.total_area_hectares = Hectares::ZERO;
+    programme.bump = ctx.bumps.programme;
+
+    emit!(ProgrammeRegistered {
+        programme: programme.key(),
+        programme_id: programme.programme_id.clone(),
+        owner: programme.owner,
+    });
+    msg!("Programme registered: {}", programme.programme_id);
+
+    fit_account_space(
+        &ctx.accounts.programme,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Length, content and eligibility checks for a new programme design.
+pub(crate) fn validate_programme_data(programme_data: &ProgrammeData, params: &ProtocolParams) -> Result<()> {
+    validate_len(&programme_data.programme_id, params.max_project_id_len)?;
+    for cid in [
+        &programme_data.design_cid,
+        &programme_data.additionality_proof_cid,
+        &programme_data.monitoring_plan_cid,
+    ] {
+        validate_cid(cid, params.max_cid_len)?;
+    }
+    validate_len(&programme_data.measurement_methodology, params.max_methodology_len)?;
+    validate_len(&programme_data.vcs_methodology, params.max_methodology_len)?;
+    validate_eligibility_criteria(&programme_data.eligibility)
+}
+
+/// Verifier signs off the programme design, after which sites can be
+/// onboarded without re-validating it.
+pub fn validate_programme(ctx: Context<ValidateProgramme>) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+    require!(
+        has_permission(
+            &ctx.accounts.verifier,
+            Role::Verifier,
+            &ctx.accounts.registry,
+            ctx.accounts.verifier_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
+    let programme = &mut ctx.accounts.programme;
+    require!(programme.validated_by.is_none(), ErrorCode::ProgrammeAlreadyValidated);
+
+    programme.validated_by = Some(ctx.accounts.verifier.key());
+    programme.validated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ProgrammeValidated {
+        programme: programme.key(),
+        verifier: ctx.accounts.verifier.key(),
+    });
+    msg!("Programme {} validated", programme.programme_id);
+
+    Ok(())
+}
+
+/// Onboards a new site under a validated programme as its own project.
+/// The site inherits the programme's design and must meet its eligibility
+/// criteria; it then goes through review and verification like any project.
+/// Remaining accounts are the spatial index accounts, as for registration.
+pub fn add_programme_site<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddProgrammeSite<'info>>,
+    site: ProgrammeSiteData,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    let programme = &ctx.accounts.programme;
+    require!(programme.validated_by.is_some(), ErrorCode::ProgrammeNotValidated);
+    validate_site_eligibility(
+        &programme.eligibility,
+        &site.location,
+        site.area_hectares,
+        site.uncertainty_percentage,
+        site.leakage_assessment,
+    )?;
+
+    let project_data = site.into_project_data(programme);
+    let params = &ctx.accounts.config.params;
+    validate_project_data(&project_data, params)?;
+
+    charge_registration_fee(
+        &ctx.accounts.owner,
+        &ctx.accounts.registry,
+        &ctx.accounts.system_program,
+        params.registration_fee_lamports,
+    )?;
+
+    claim_geo_cells(
+        ctx.accounts.project.key(),
+        &project_data.location.polygon_coordinates,
+        None,
+        params,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+    )?;
+
+    let project = &mut ctx.accounts.project;
+    init_blue_project(
+        project,
+        project_data,
+        ctx.accounts.owner.key(),
+        ctx.bumps.project,
+        Clock::get()?.unix_timestamp,
+    );
+    project.programme = Some(ctx.accounts.programme.key());
+
+    reserve_project_id(
+        &mut ctx.accounts.project_index,
+        &project.project_id,
+        project.key(),
+        ctx.bumps.project_index,
+    )?;
+
+    ctx.accounts.registry.total_projects += 1;
+
+    let programme = &mut ctx.accounts.programme;
+    let site_index = programme.site_count;
+    programme.site_count += 1;
+    programme.total_area_hectares = programme
+        .total_area_hectares
+        .checked_add(project.area_hectares)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    emit!(ProgrammeSiteAdded {
+        programme: programme.key(),
+        project: project.key(),
+        project_id: project.project_id.clone(),
+        site_index,
+        area_hectares: project.area_hectares,
+    });
+    msg!(
+        "Site {} added to programme {} (#{})",
+        project.project_id,
+        programme.programme_id,
+        site_index
+    );
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+    )
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*};
+
+pub fn propose_project_transfer(ctx: Context<ManageProjectTransfer>, new_owner: Pubkey) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    require_keys_eq!(ctx.accounts.authority.key(), project.owner, ErrorCode::NotProjectOwner);
+
+    project.pending_owner = Some(new_owner);
+
+    emit!(ProjectTransferProposed {
+        project: project.key(),
+        owner: project.owner,
+        pending_owner: new_owner,
+    });
+    msg!("Project {} transfer proposed to {}", project.project_id, new_owner);
+
+    Ok(())
+}
+
+/// Completes a transfer. The project keeps its address because the PDA is
+/// seeded by the immutable creator, not the current owner.
+pub fn accept_project_transfer(ctx: Context<ManageProjectTransfer>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let new_owner = project.pending_owner.ok_or(ErrorCode::NoPendingProjectTransfer)?;
+    require_keys_eq!(ctx.accounts.authority.key(), new_owner, ErrorCode::NotPendingProjectOwner);
+
+    let previous_owner = project.owner;
+    project.owner = new_owner;
+    project.pending_owner = None;
+
+    emit!(ProjectTransferAccepted {
+        project: project.key(),
+        previous_owner,
+        new_owner,
+    });
+    msg!("Project {} owner changed from {} to {}", project.project_id, previous_owner, new_owner);
+
+    Ok(())
+}
+
+pub fn cancel_project_transfer(ctx: Context<ManageProjectTransfer>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    require_keys_eq!(ctx.accounts.authority.key(), project.owner, ErrorCode::NotProjectOwner);
+
+    let cancelled_owner = project.pending_owner.take().ok_or(ErrorCode::NoPendingProjectTransfer)?;
+
+    emit!(ProjectTransferCancelled {
+        project: project.key(),
+        owner: project.owner,
+        cancelled_owner,
+    });
+    msg!("Project {} transfer to {} cancelled", project.project_id, cancelled_owner);
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{access::execute_proponent_proposal, validation::validate_proponents};
+use crate::instructions::{contexts::*, errors::ErrorCode, events::*, resize::fit_account_space};
+
+/// Replaces the project's co-proponents, their shares and the approval
+/// threshold. The owner sets the first list; after that the change itself
+/// needs an approved UpdateProponents proposal. Passing an empty list with
+/// a zero threshold returns the project to owner-only control.
+pub fn set_project_proponents(
+    ctx: Context<SetProjectProponents>,
+    proponents: Vec<Proponent>,
+    threshold: u8,
+) -> Result<()> {
+    validate_proponents(&proponents, threshold)?;
+
+    let project = &mut ctx.accounts.project;
+    if project.proponents.is_empty() {
+        require_keys_eq!(ctx.accounts.authority.key(), project.owner, ErrorCode::Unauthorized);
+    } else {
+        execute_proponent_proposal(
+            &ctx.accounts.authority,
+            project,
+            ctx.accounts.proposal.as_mut(),
+            &ProponentAction::UpdateProponents {
+                proponents: proponents.clone(),
+                threshold,
+            },
+        )?;
+    }
+
+    project.proponents = proponents;
+    project.proponent_threshold = threshold;
+    // Approvals collected under the old list no longer count
+    project.proponent_nonce += 1;
+
+    emit!(ProponentsUpdated {
+        project: project.key(),
+        proponents: project.proponents.clone(),
+        threshold,
+    });
+    msg!(
+        "Project {} proponents updated: {} of {}",
+        project.project_id,
+        threshold,
+        project.proponents.len()
+    );
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+    )
+}
+
+pub fn create_proponent_proposal(
+    ctx: Context<CreateProponentProposal>,
+    action: ProponentAction,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(project.is_proponent(&proposer), ErrorCode::NotProponent);
+    if let ProponentAction::UpdateProponents { proponents, threshold } = &action {
+        validate_proponents(proponents, *threshold)?;
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.project = project.key();
+    proposal.index = project.proponent_proposal_count;
+    proposal.proponent_nonce = project.proponent_nonce;
+    proposal.proposer = proposer;
+    proposal.action = action.clone();
+    proposal.approvals = vec![proposer];
+    proposal.threshold = project.proponent_threshold;
+    proposal.created_at = Clock::get()?.unix_timestamp;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    project.proponent_proposal_count += 1;
+
+    emit!(ProponentProposalCreated {
+        proposal: proposal.key(),
+        project: project.key(),
+        proposer,
+        action,
+    });
+    msg!("Proponent proposal {} created for {}", proposal.index, project.project_id);
+
+    Ok(())
+}
+
+pub fn approve_proponent_proposal(ctx: Context<ApproveProponentProposal>) -> Result<()> {
+    let project = &ctx.accounts.project;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = ctx.accounts.approver.key();
+
+    require!(project.is_proponent(&approver), ErrorCode::NotProponent);
+    require!(
+        proposal.proponent_nonce == project.proponent_nonce,
+        ErrorCode::StaleProposal
+    );
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+    require!(!proposal.approvals.contains(&approver), ErrorCode::AlreadyApproved);
+
+    proposal.approvals.push(approver);
+
+    emit!(ProponentProposalApproved {
+        proposal: proposal.key(),
+        approver,
+        approvals: proposal.approvals.len() as u8,
+    });
+    msg!(
+        "Proponent proposal {} approved ({}/{})",
+        proposal.index,
+        proposal.approvals.len(),
+        proposal.threshold
+    );
+
+    Ok(())
+}
+
+/// Withdraws a proponent proposal and returns its rent to the proposer.
+pub fn cancel_proponent_proposal(ctx: Context<CancelProponentProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(!proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+
+    emit!(ProponentProposalCancelled {
+        proposal: proposal.key(),
+    });
+    msg!("Proponent proposal {} cancelled", proposal.index);
+
+    Ok(())
+}
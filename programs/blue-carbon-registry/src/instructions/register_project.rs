@@ -1,6 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 use crate::models::*;
-use crate::instructions::contexts::*;
+use crate::auth_utils::{
+    access::authorize_admin_action,
+    validation::{
+        require_not_paused, validate_carbon_science, validate_cid, validate_len, validate_location,
+        validate_max_entries, validate_methodology_conformance,
+    },
+};
+use crate::instructions::{
+    contexts::*, errors::ErrorCode, resize::fit_account_space, spatial_index::claim_geo_cells,
+};
 
 pub fn register_project(
     ctx: Context<RegisterProject>,
@@ -8,18 +18,42 @@ pub fn register_project(
     ipfs_cid: String,
     carbon_tons_estimated: u64,
 ) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    let params = &ctx.accounts.config.params;
+    validate_len(&project_id, params.max_project_id_len)?;
+    validate_cid(&ipfs_cid, params.max_cid_len)?;
+
+    charge_registration_fee(
+        &ctx.accounts.project_owner,
+        &ctx.accounts.registry,
+        &ctx.accounts.system_program,
+        params.registration_fee_lamports,
+    )?;
+
     let project_account = &mut ctx.accounts.project;
     let registry = &mut ctx.accounts.registry;
 
     // Set the initial project data on the new account.
+    project_account.version = Project::VERSION;
     project_account.project_id = project_id.clone();
     project_account.owner = ctx.accounts.project_owner.key();
+    project_account.creator = project_account.owner;
+    project_account.pending_owner = None;
     project_account.ipfs_cid = ipfs_cid.clone();
     project_account.carbon_tons_estimated = carbon_tons_estimated;
     project_account.verification_status = VerificationStatus::Pending;
     project_account.credits_issued = 0;
     project_account.tokens_minted = 0;
     project_account.bump = ctx.bumps.project;
+    project_account.amendment_count = 0;
+
+    reserve_project_id(
+        &mut ctx.accounts.project_index,
+        &project_id,
+        project_account.key(),
+        ctx.bumps.project_index,
+    )?;
 
     // Update global registry
     registry.total_projects += 1;
@@ -29,19 +63,125 @@ pub fn register_project(
     msg!("IPFS CID: {}", project_account.ipfs_cid);
     msg!("Estimated Carbon Tons: {}", project_account.carbon_tons_estimated);
 
-    Ok(())
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.project_owner,
+        &ctx.accounts.system_program,
+    )
 }
 
-pub fn register_blue_carbon_project(
-    ctx: Context<RegisterBlueProject>,
+pub fn register_blue_carbon_project<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterBlueProject<'info>>,
     project_data: BlueProjectData,
 ) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+
+    let params = &ctx.accounts.config.params;
+    validate_project_data(&project_data, params)?;
+    if let Some(methodology) = ctx.accounts.methodology.as_deref() {
+        require!(methodology.active, ErrorCode::MethodologyInactive);
+        validate_methodology_conformance(
+            methodology,
+            &project_data.vcs_methodology,
+            &project_data.ecosystem_type,
+            project_data.uncertainty_percentage,
+            project_data.leakage_assessment,
+        )?;
+    }
+
+    charge_registration_fee(
+        &ctx.accounts.project_owner,
+        &ctx.accounts.registry,
+        &ctx.accounts.system_program,
+        params.registration_fee_lamports,
+    )?;
+
+    claim_geo_cells(
+        ctx.accounts.project.key(),
+        &project_data.location.polygon_coordinates,
+        None,
+        params,
+        &ctx.accounts.project_owner,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+    )?;
+
     let project = &mut ctx.accounts.project;
     let registry = &mut ctx.accounts.registry;
 
+    init_blue_project(
+        project,
+        project_data,
+        ctx.accounts.project_owner.key(),
+        ctx.bumps.project,
+        Clock::get()?.unix_timestamp,
+    );
+    project.methodology = ctx.accounts.methodology.as_ref().map(|methodology| methodology.key());
+
+    reserve_project_id(
+        &mut ctx.accounts.project_index,
+        &project.project_id,
+        project.key(),
+        ctx.bumps.project_index,
+    )?;
+
+    // Update registry
+    registry.total_projects += 1;
+
+    msg!("Blue Carbon Project registered: {}", project.project_id);
+    msg!("Ecosystem Type: {:?}", project.ecosystem_type);
+    msg!("Location: {}, {}", project.location.latitude, project.location.longitude);
+    msg!("Area: {} hectares", project.area_hectares);
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.project_owner,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Length, content and science checks shared by every way of registering
+/// a full blue carbon project.
+pub(crate) fn validate_project_data(project_data: &BlueProjectData, params: &ProtocolParams) -> Result<()> {
+    validate_len(&project_data.project_id, params.max_project_id_len)?;
+    for cid in [
+        &project_data.ipfs_cid,
+        &project_data.additionality_proof_cid,
+        &project_data.monitoring_plan_cid,
+    ] {
+        validate_cid(cid, params.max_cid_len)?;
+    }
+    validate_len(&project_data.measurement_methodology, params.max_methodology_len)?;
+    validate_len(&project_data.vcs_methodology, params.max_methodology_len)?;
+    validate_len(&project_data.location.region_name, params.max_name_len)?;
+    validate_location(&project_data.location, project_data.area_hectares)?;
+    validate_carbon_science(
+        Some(project_data.uncertainty_percentage),
+        Some(project_data.leakage_assessment),
+    )?;
+    validate_max_entries(project_data.species_composition.len(), Project::MAX_SPECIES)?;
+    for species in &project_data.species_composition {
+        validate_len(species, params.max_name_len)?;
+    }
+    validate_max_entries(project_data.co_benefits.len(), Project::MAX_CO_BENEFITS)?;
+    Ok(())
+}
+
+/// Fills a freshly created project account from `project_data`, dated
+/// `established_at`.
+pub(crate) fn init_blue_project(
+    project: &mut Project,
+    project_data: BlueProjectData,
+    owner: Pubkey,
+    bump: u8,
+    established_at: i64,
+) {
     // Set comprehensive project data
-    project.project_id = project_data.project_id.clone();
-    project.owner = ctx.accounts.project_owner.key();
+    project.version = Project::VERSION;
+    project.project_id = project_data.project_id;
+    project.owner = owner;
+    project.creator = owner;
+    project.pending_owner = None;
     project.ipfs_cid = project_data.ipfs_cid;
     project.carbon_tons_estimated = project_data.carbon_tons_estimated;
     project.verification_status = VerificationStatus::Pending;
@@ -52,7 +192,7 @@ pub fn register_blue_carbon_project(
     project.area_hectares = project_data.area_hectares;
     project.species_composition = project_data.species_composition;
     project.biodiversity_index = project_data.biodiversity_index;
-    project.establishment_date = Clock::get()?.unix_timestamp;
+    project.establishment_date = established_at;
     
     // Carbon science data
     project.above_ground_biomass = project_data.above_ground_biomass;
@@ -83,15 +223,68 @@ pub fn register_blue_carbon_project(
     project.price_per_ton = project_data.price_per_ton;
     project.available_quantity = project_data.carbon_tons_estimated;
     
-    project.bump = ctx.bumps.project;
+    project.bump = bump;
+    project.amendment_count = 0;
+}
 
-    // Update registry
-    registry.total_projects += 1;
+/// Backfills the ID index for a project registered before IDs were reserved.
+/// Where legacy projects share an ID, the admin picks the canonical one.
+pub fn index_existing_project(ctx: Context<IndexExistingProject>) -> Result<()> {
+    authorize_admin_action(
+        &ctx.accounts.admin,
+        &ctx.accounts.registry,
+        ctx.accounts.proposal.as_mut(),
+        AdminAction::IndexExistingProject { project: ctx.accounts.project.key() },
+    )?;
 
-    msg!("Blue Carbon Project registered: {}", project.project_id);
-    msg!("Ecosystem Type: {:?}", project.ecosystem_type);
-    msg!("Location: {}, {}", project.location.latitude, project.location.longitude);
-    msg!("Area: {} hectares", project.area_hectares);
+    let project = &ctx.accounts.project;
+    reserve_project_id(
+        &mut ctx.accounts.project_index,
+        &project.project_id,
+        project.key(),
+        ctx.bumps.project_index,
+    )
+}
+
+pub(crate) fn reserve_project_id(
+    index: &mut ProjectIdIndex,
+    project_id: &str,
+    project: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    index.project_id = project_id.to_string();
+    index.project = project;
+    index.registered_at = Clock::get()?.unix_timestamp;
+    index.bump = bump;
+
+    msg!("Project ID {} reserved for {}", project_id, project);
+
+    Ok(())
+}
+
+/// Pays the configured registration fee into the registry PDA.
+pub(crate) fn charge_registration_fee<'info>(
+    payer: &Signer<'info>,
+    registry: &Account<'info, GlobalRegistry>,
+    system_program: &Program<'info, System>,
+    lamports: u64,
+) -> Result<()> {
+    if lamports == 0 {
+        return Ok(());
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: registry.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+
+    msg!("Registration fee paid: {} lamports", lamports);
 
     Ok(())
 }
@@ -0,0 +1,572 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::models::*;
+use crate::instructions::errors::ErrorCode;
+
+// Accounts sized by payload stay within what one instruction may allocate
+pub const MAX_ACCOUNT_SPACE: usize = 10_240;
+
+/// Resizes `account` to exactly fit its current contents. Growing tops up
+/// rent from `payer`; shrinking refunds the excess rent to `payer`.
+pub(crate) fn fit_account_space<'info, T>(
+    account: &Account<'info, T>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone + DynamicSpace,
+{
+    let space = account.space();
+    require!(space <= MAX_ACCOUNT_SPACE, ErrorCode::AccountTooLarge);
+    resize_account(&account.to_account_info(), payer, system_program, space)
+}
+
+/// Sets the data length of a program-owned account, keeping it exactly
+/// rent exempt.
+pub(crate) fn resize_account<'info>(
+    target: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+) -> Result<()> {
+    let current = target.data_len();
+    if space == current {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = target.lamports();
+    if lamports < rent {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    } else if space < current && lamports > rent {
+        let refund = lamports - rent;
+        **target.try_borrow_mut_lamports()? -= refund;
+        **payer.to_account_info().try_borrow_mut_lamports()? += refund;
+    }
+
+    target.realloc(space, false)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixed::*;
+    use crate::auth_utils::validation::validate_methodology_data;
+    use crate::instructions::{
+        marketplace::validate_listing_data,
+        monitoring::validate_monitoring_input,
+        programme::validate_programme_data,
+        register_project::{init_blue_project, validate_project_data},
+        track_impact::validate_report_data,
+        verify_project::validate_verifier_profile,
+    };
+
+    // sha2-256("blue carbon") as base32 CIDv1
+    const CID: &str = "bafybeiawrk5y7764snt6b4ftalaxingltrvts2c2i657e4s5hxtsuqir2a";
+
+    fn text(len: u16) -> String {
+        "x".repeat(len as usize)
+    }
+
+    fn error(code: ErrorCode) -> Result<()> {
+        Err(code.into())
+    }
+
+    // The account is created with room for everything its instruction
+    // copies in, within what fit_account_space may later grow it to
+    fn assert_fits<T: DynamicSpace>(init_space: usize, account: &T) {
+        assert!(account.space() <= init_space, "{} > {init_space}", account.space());
+        assert!(init_space <= MAX_ACCOUNT_SPACE, "{init_space}");
+    }
+
+    // A convex boundary with the most vertices allowed: 31 points on a
+    // parabola, closed
+    fn max_ring() -> Vec<[Degrees; 2]> {
+        let mut ring: Vec<[Degrees; 2]> = (0..GeoLocation::MAX_POLYGON_VERTICES as i32 - 1)
+            .map(|i| [Degrees(i * 10_000), Degrees(i * i * 1_000)])
+            .collect();
+        ring.push(ring[0]);
+        ring
+    }
+
+    fn max_location(params: &ProtocolParams) -> GeoLocation {
+        let polygon_coordinates = max_ring();
+        let [longitude, latitude] = polygon_coordinates[0];
+        GeoLocation {
+            latitude,
+            longitude,
+            polygon_coordinates,
+            country_code: "ID".to_string(),
+            region_name: text(params.max_name_len),
+        }
+    }
+
+    // Every string and list at its limit; `cid` stands in for each CID
+    fn max_project_data(params: &ProtocolParams, cid: &str) -> BlueProjectData {
+        let location = max_location(params);
+        BlueProjectData {
+            project_id: text(params.max_project_id_len),
+            ipfs_cid: cid.to_string(),
+            carbon_tons_estimated: u64::MAX,
+            ecosystem_type: EcosystemType::Mangrove,
+            area_hectares: location.polygon_area(),
+            location,
+            species_composition: vec![text(params.max_name_len); Project::MAX_SPECIES],
+            biodiversity_index: Measure(700_000),
+            above_ground_biomass: Tonnes(100_000_000),
+            below_ground_biomass: Tonnes(40_000_000),
+            soil_carbon_0_30cm: Tonnes(60_000_000),
+            soil_carbon_30_100cm: Tonnes(80_000_000),
+            sequestration_rate_annual: Tonnes(5_000_000),
+            measurement_methodology: text(params.max_methodology_len),
+            uncertainty_percentage: BasisPoints(1_000),
+            vcs_methodology: text(params.max_methodology_len),
+            additionality_proof_cid: cid.to_string(),
+            permanence_guarantee_years: 30,
+            leakage_assessment: BasisPoints(500),
+            monitoring_plan_cid: cid.to_string(),
+            baseline_ecosystem_health: Measure(60_000_000),
+            species_count_baseline: 12,
+            co_benefits: vec![CoBenefit::CoastalProtection; Project::MAX_CO_BENEFITS],
+            vintage_year: 2025,
+            price_per_ton: 15,
+        }
+    }
+
+    fn max_programme_data(params: &ProtocolParams, cid: &str) -> ProgrammeData {
+        ProgrammeData {
+            programme_id: text(params.max_project_id_len),
+            design_cid: cid.to_string(),
+            ecosystem_type: EcosystemType::Mangrove,
+            vcs_methodology: text(params.max_methodology_len),
+            measurement_methodology: text(params.max_methodology_len),
+            additionality_proof_cid: cid.to_string(),
+            monitoring_plan_cid: cid.to_string(),
+            permanence_guarantee_years: 30,
+            eligibility: EligibilityCriteria {
+                country_codes: vec!["ID".to_string(); EligibilityCriteria::MAX_COUNTRIES],
+                boundary: max_ring(),
+                min_site_area: Hectares::ZERO,
+                max_site_area: Hectares(u64::MAX),
+                max_uncertainty: BasisPoints(2_000),
+                max_leakage: BasisPoints(1_000),
+            },
+        }
+    }
+
+    fn max_methodology_data(cid: &str) -> MethodologyData {
+        let ecosystems = [
+            EcosystemType::Mangrove,
+            EcosystemType::Seagrass,
+            EcosystemType::SaltMarsh,
+            EcosystemType::MixedBlueCarbon,
+        ];
+        MethodologyData {
+            methodology_id: text(Methodology::ID_LEN),
+            methodology_version: text(Methodology::VERSION_LEN),
+            document_cid: cid.to_string(),
+            emission_factors: ecosystems
+                .iter()
+                .map(|ecosystem_type| EmissionFactor {
+                    ecosystem_type: ecosystem_type.clone(),
+                    above_ground_biomass: Tonnes(100_000_000),
+                    below_ground_biomass: Tonnes(40_000_000),
+                    soil_carbon: Tonnes(140_000_000),
+                    sequestration_rate_annual: Tonnes(5_000_000),
+                })
+                .collect(),
+            eligible_ecosystems: ecosystems.to_vec(),
+            monitoring_interval_days: 365,
+            buffer_percentage: BasisPoints(1_500),
+            max_uncertainty: BasisPoints(2_000),
+            uncertainty_allowance: BasisPoints(1_000),
+            max_leakage: BasisPoints(1_000),
+        }
+    }
+
+    fn max_verifier_data(params: &ProtocolParams) -> VerifierData {
+        VerifierData {
+            verifier_type: VerifierType::CertificationBody,
+            credentials: vec![text(params.max_name_len); VerificationNode::MAX_CREDENTIALS],
+            specializations: vec![EcosystemType::Mangrove; VerificationNode::MAX_SPECIALIZATIONS],
+        }
+    }
+
+    fn max_monitoring_input(params: &ProtocolParams, cid: &str) -> MonitoringDataInput {
+        MonitoringDataInput {
+            project_id: text(params.max_project_id_len),
+            satellite_imagery_cid: cid.to_string(),
+            ndvi_index: Measure(810_000),
+            water_quality: WaterQuality { ph_level: Measure(7_900_000), ..Default::default() },
+            temperature_data: vec![Measure(28_500_000); MonitoringData::MAX_TEMPERATURE_READINGS],
+            tide_data: vec![
+                TideReading {
+                    timestamp: 1_700_000_000,
+                    tide_height: Measure(1_820_000),
+                    tide_type: text(params.max_name_len),
+                };
+                MonitoringData::MAX_TIDE_READINGS
+            ],
+            iot_sensor_data: vec![
+                SensorReading {
+                    sensor_id: text(params.max_name_len),
+                    timestamp: 1_700_000_000,
+                    co2_flux: Measure(-2_500_000),
+                    soil_moisture: BasisPoints(4_550),
+                    ph_level: Measure(7_100_000),
+                    temperature: Measure(29_000_000),
+                    humidity: BasisPoints(8_025),
+                };
+                MonitoringData::MAX_SENSOR_READINGS
+            ],
+            ecosystem_health_score: Measure(72_500_000),
+        }
+    }
+
+    fn max_listing_data(params: &ProtocolParams) -> MarketplaceListingData {
+        MarketplaceListingData {
+            project_id: text(params.max_project_id_len),
+            vintage_year: 2025,
+            quantity_available: u64::MAX,
+            price_per_ton: u64::MAX,
+            certification_standards: vec![text(params.max_name_len); CarbonCreditListing::MAX_CERTIFICATIONS],
+            expiry_date: i64::MAX,
+        }
+    }
+
+    fn max_report_data(params: &ProtocolParams, cid: &str) -> ImpactReportData {
+        ImpactReportData {
+            project_id: text(params.max_project_id_len),
+            reporting_period_start: 1_700_000_000,
+            reporting_period_end: 1_731_536_000,
+            carbon_sequestered: Tonnes(812_500_000),
+            community_benefits: vec![
+                CommunityBenefit {
+                    benefit_type: text(params.max_name_len),
+                    households_affected: 120,
+                    jobs_created: 14,
+                    income_increase_percentage: BasisPoints(1_250),
+                    capacity_building_programs: 2,
+                };
+                ImpactReport::MAX_COMMUNITY_BENEFITS
+            ],
+            economic_impact: EconomicImpact::default(),
+            sdg_contributions: vec![14; ImpactReport::MAX_SDG_CONTRIBUTIONS],
+            verification_report_cid: cid.to_string(),
+            species_count_current: 15,
+        }
+    }
+
+    // Populated as register_blue_carbon_project does, under a methodology
+    fn registered_project(project_data: BlueProjectData) -> Project {
+        let mut project = Project::default();
+        init_blue_project(&mut project, project_data, Pubkey::new_unique(), 255, 1_700_000_000);
+        project.methodology = Some(Pubkey::new_unique());
+        project
+    }
+
+    #[test]
+    fn registered_project_fits_its_init_space() {
+        let params = ProtocolParams::default();
+        let project_data = max_project_data(&params, &text(params.max_cid_len));
+        let init_space = Project::init_space(serialized_len(&project_data));
+        assert_fits(init_space, &registered_project(project_data));
+    }
+
+    #[test]
+    fn programme_and_its_sites_fit_their_init_space() {
+        let params = ProtocolParams::default();
+        let cid = text(params.max_cid_len);
+        let programme_data = max_programme_data(&params, &cid);
+        let init_space = Programme::init_space(serialized_len(&programme_data));
+
+        let mut programme = Programme {
+            version: Programme::VERSION,
+            programme_id: programme_data.programme_id,
+            owner: Pubkey::new_unique(),
+            design_cid: programme_data.design_cid,
+            ecosystem_type: programme_data.ecosystem_type,
+            vcs_methodology: programme_data.vcs_methodology,
+            measurement_methodology: programme_data.measurement_methodology,
+            additionality_proof_cid: programme_data.additionality_proof_cid,
+            monitoring_plan_cid: programme_data.monitoring_plan_cid,
+            permanence_guarantee_years: programme_data.permanence_guarantee_years,
+            eligibility: programme_data.eligibility,
+            ..Default::default()
+        };
+        assert_fits(init_space, &programme);
+
+        // Sites are added once the design is validated
+        programme.validated_by = Some(Pubkey::new_unique());
+        let project_data = max_project_data(&params, &cid);
+        let site = ProgrammeSiteData {
+            project_id: project_data.project_id,
+            ipfs_cid: project_data.ipfs_cid,
+            carbon_tons_estimated: project_data.carbon_tons_estimated,
+            location: project_data.location,
+            area_hectares: project_data.area_hectares,
+            species_composition: project_data.species_composition,
+            biodiversity_index: project_data.biodiversity_index,
+            above_ground_biomass: project_data.above_ground_biomass,
+            below_ground_biomass: project_data.below_ground_biomass,
+            soil_carbon_0_30cm: project_data.soil_carbon_0_30cm,
+            soil_carbon_30_100cm: project_data.soil_carbon_30_100cm,
+            sequestration_rate_annual: project_data.sequestration_rate_annual,
+            uncertainty_percentage: project_data.uncertainty_percentage,
+            leakage_assessment: project_data.leakage_assessment,
+            baseline_ecosystem_health: project_data.baseline_ecosystem_health,
+            species_count_baseline: project_data.species_count_baseline,
+            co_benefits: project_data.co_benefits,
+            vintage_year: project_data.vintage_year,
+            price_per_ton: project_data.price_per_ton,
+        };
+        let init_space = Project::init_space(serialized_len(&site) + serialized_len(&programme));
+        let mut project = Project::default();
+        init_blue_project(
+            &mut project,
+            site.into_project_data(&programme),
+            programme.owner,
+            255,
+            1_700_000_000,
+        );
+        project.programme = Some(Pubkey::new_unique());
+        assert_fits(init_space, &project);
+    }
+
+    #[test]
+    fn methodology_fits_its_init_space() {
+        let params = ProtocolParams::default();
+        let data = max_methodology_data(&text(params.max_cid_len));
+        let init_space = Methodology::init_space(serialized_len(&data));
+        let methodology = Methodology {
+            version: Methodology::VERSION,
+            methodology_id: data.methodology_id,
+            methodology_version: data.methodology_version,
+            document_cid: data.document_cid,
+            eligible_ecosystems: data.eligible_ecosystems,
+            emission_factors: data.emission_factors,
+            monitoring_interval_days: data.monitoring_interval_days,
+            buffer_percentage: data.buffer_percentage,
+            max_uncertainty: data.max_uncertainty,
+            uncertainty_allowance: data.uncertainty_allowance,
+            max_leakage: data.max_leakage,
+            active: true,
+            ..Default::default()
+        };
+        assert_fits(init_space, &methodology);
+    }
+
+    #[test]
+    fn verifier_fits_its_init_space() {
+        let data = max_verifier_data(&ProtocolParams::default());
+        let init_space = VerificationNode::init_space(serialized_len(&data));
+        let verifier = VerificationNode {
+            version: VerificationNode::VERSION,
+            verifier_pubkey: Pubkey::new_unique(),
+            verifier_type: data.verifier_type,
+            credentials: data.credentials,
+            is_active: true,
+            specializations: data.specializations,
+            ..Default::default()
+        };
+        assert_fits(init_space, &verifier);
+    }
+
+    #[test]
+    fn monitoring_data_fits_its_init_space() {
+        let params = ProtocolParams::default();
+        let project_id = text(params.max_project_id_len);
+        let input = max_monitoring_input(&params, &text(params.max_cid_len));
+        let init_space = MonitoringData::init_space(serialized_len(&project_id) + serialized_len(&input));
+        let monitoring = MonitoringData {
+            version: MonitoringData::VERSION,
+            project_id,
+            satellite_imagery_cid: input.satellite_imagery_cid,
+            ndvi_index: input.ndvi_index,
+            water_quality: input.water_quality,
+            temperature_data: input.temperature_data,
+            tide_data: input.tide_data,
+            iot_sensor_data: input.iot_sensor_data,
+            ecosystem_health_score: input.ecosystem_health_score,
+            ..Default::default()
+        };
+        assert_fits(init_space, &monitoring);
+    }
+
+    #[test]
+    fn listing_fits_its_init_space() {
+        let params = ProtocolParams::default();
+        let project_id = text(params.max_project_id_len);
+        let data = max_listing_data(&params);
+        let init_space = CarbonCreditListing::init_space(serialized_len(&project_id) + serialized_len(&data));
+        // Co-benefits come from the project rather than the payload
+        let listing = CarbonCreditListing {
+            version: CarbonCreditListing::VERSION,
+            project_id,
+            seller: Pubkey::new_unique(),
+            vintage_year: data.vintage_year,
+            quantity_available: data.quantity_available,
+            price_per_ton: data.price_per_ton,
+            co_benefits: vec![CoBenefit::CoastalProtection; Project::MAX_CO_BENEFITS],
+            certification_standards: data.certification_standards,
+            expiry_date: data.expiry_date,
+            is_active: true,
+            ..Default::default()
+        };
+        assert_fits(init_space, &listing);
+    }
+
+    #[test]
+    fn impact_report_fits_its_init_space() {
+        let params = ProtocolParams::default();
+        let project_id = text(params.max_project_id_len);
+        let data = max_report_data(&params, &text(params.max_cid_len));
+        let init_space = ImpactReport::init_space(serialized_len(&project_id) + serialized_len(&data));
+        let report = ImpactReport {
+            version: ImpactReport::VERSION,
+            project_id,
+            reporting_period_start: data.reporting_period_start,
+            reporting_period_end: data.reporting_period_end,
+            carbon_sequestered: data.carbon_sequestered,
+            community_benefits: data.community_benefits,
+            economic_impact: data.economic_impact,
+            sdg_contributions: data.sdg_contributions,
+            verification_report_cid: data.verification_report_cid,
+            ..Default::default()
+        };
+        assert_fits(init_space, &report);
+    }
+
+    #[test]
+    fn project_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_project_data(&max_project_data(&params, CID), &params).is_ok());
+
+        let mut data = max_project_data(&params, CID);
+        data.project_id.push('x');
+        assert_eq!(validate_project_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_project_data(&params, CID);
+        data.vcs_methodology.push('x');
+        assert_eq!(validate_project_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_project_data(&params, CID);
+        data.species_composition[0].push('x');
+        assert_eq!(validate_project_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_project_data(&params, CID);
+        data.species_composition.push(String::new());
+        assert_eq!(validate_project_data(&data, &params), error(ErrorCode::TooManyEntries));
+
+        let mut data = max_project_data(&params, CID);
+        data.co_benefits.push(CoBenefit::WaterQuality);
+        assert_eq!(validate_project_data(&data, &params), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn programme_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_programme_data(&max_programme_data(&params, CID), &params).is_ok());
+
+        let mut data = max_programme_data(&params, CID);
+        data.measurement_methodology.push('x');
+        assert_eq!(validate_programme_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_programme_data(&params, CID);
+        data.eligibility.country_codes.push("ID".to_string());
+        assert_eq!(validate_programme_data(&data, &params), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn methodology_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_methodology_data(&max_methodology_data(CID), params.max_cid_len).is_ok());
+
+        let mut data = max_methodology_data(CID);
+        data.methodology_version.push('x');
+        assert_eq!(validate_methodology_data(&data, params.max_cid_len), error(ErrorCode::InputTooLong));
+
+        let mut data = max_methodology_data(CID);
+        data.eligible_ecosystems.push(EcosystemType::Mangrove);
+        assert_eq!(validate_methodology_data(&data, params.max_cid_len), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn verifier_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        let check = |data: &VerifierData| validate_verifier_profile(&data.credentials, &data.specializations, &params);
+        assert!(check(&max_verifier_data(&params)).is_ok());
+
+        let mut data = max_verifier_data(&params);
+        data.credentials[0].push('x');
+        assert_eq!(check(&data), error(ErrorCode::InputTooLong));
+
+        let mut data = max_verifier_data(&params);
+        data.credentials.push(String::new());
+        assert_eq!(check(&data), error(ErrorCode::TooManyEntries));
+
+        let mut data = max_verifier_data(&params);
+        data.specializations.push(EcosystemType::Seagrass);
+        assert_eq!(check(&data), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn monitoring_input_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_monitoring_input(&max_monitoring_input(&params, CID), &params).is_ok());
+
+        let mut input = max_monitoring_input(&params, CID);
+        input.tide_data[0].tide_type.push('x');
+        assert_eq!(validate_monitoring_input(&input, &params), error(ErrorCode::InputTooLong));
+
+        let mut input = max_monitoring_input(&params, CID);
+        input.iot_sensor_data[0].sensor_id.push('x');
+        assert_eq!(validate_monitoring_input(&input, &params), error(ErrorCode::InputTooLong));
+
+        let mut input = max_monitoring_input(&params, CID);
+        input.temperature_data.push(Measure::ZERO);
+        assert_eq!(validate_monitoring_input(&input, &params), error(ErrorCode::TooManyEntries));
+
+        let mut input = max_monitoring_input(&params, CID);
+        input.iot_sensor_data.push(input.iot_sensor_data[0].clone());
+        assert_eq!(validate_monitoring_input(&input, &params), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn listing_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_listing_data(&max_listing_data(&params), &params).is_ok());
+
+        let mut data = max_listing_data(&params);
+        data.certification_standards[0].push('x');
+        assert_eq!(validate_listing_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_listing_data(&params);
+        data.certification_standards.push(String::new());
+        assert_eq!(validate_listing_data(&data, &params), error(ErrorCode::TooManyEntries));
+    }
+
+    #[test]
+    fn report_data_over_its_limits_is_rejected() {
+        let params = ProtocolParams::default();
+        assert!(validate_report_data(&max_report_data(&params, CID), &params).is_ok());
+
+        let mut data = max_report_data(&params, CID);
+        data.community_benefits[0].benefit_type.push('x');
+        assert_eq!(validate_report_data(&data, &params), error(ErrorCode::InputTooLong));
+
+        let mut data = max_report_data(&params, CID);
+        data.sdg_contributions.push(15);
+        assert_eq!(validate_report_data(&data, &params), error(ErrorCode::TooManyEntries));
+    }
+}
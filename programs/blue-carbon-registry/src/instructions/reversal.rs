@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Transfer};
+use crate::models::*;
+use crate::fixed::*;
+use crate::auth_utils::{
+    access::has_permission,
+    validation::{require_not_paused, validate_cid},
+};
+use crate::instructions::{
+    contexts::*,
+    errors::ErrorCode,
+    events::{ReversalCompensated, ReversalReported},
+    lifecycle::apply_transition,
+};
+
+/// Auditor reports stored carbon lost from a project, e.g. mangroves
+/// destroyed by a cyclone, with the monitoring evidence behind it.
+///
+/// Only a project that has issued credits can have a reversal, and the
+/// tokens written off across all its reversals never exceed what it issued.
+///
+/// An issuing project is suspended. The token equivalent of the loss is
+/// cancelled from the buffer pool as far as this project's own
+/// contributions to it cover it, so one project's loss never burns another
+/// project's buffer; whatever they cannot cover becomes the owner's
+/// liability. An intentional loss is
+/// charged to the owner in full, on top of the buffer cancellation, so the
+/// pool is made whole again. Minting stays blocked until the liability is
+/// paid off with compensate_reversal.
+pub fn report_reversal(
+    ctx: Context<ReportReversal>,
+    evidence_cid: String,
+    affected_area: Hectares,
+    co2e_lost: Tonnes,
+    intentional: bool,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+    require!(
+        has_permission(
+            &ctx.accounts.auditor,
+            Role::Auditor,
+            &ctx.accounts.registry,
+            ctx.accounts.auditor_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+    let params = &ctx.accounts.config.params;
+    validate_cid(&evidence_cid, params.max_cid_len)?;
+
+    let project = &ctx.accounts.project;
+    require!(
+        co2e_lost > Tonnes::ZERO
+            && affected_area > Hectares::ZERO
+            && (project.area_hectares == Hectares::ZERO || affected_area <= project.area_hectares),
+        ErrorCode::InvalidReversal
+    );
+    require!(project.credits_issued > 0, ErrorCode::NoCreditsIssued);
+
+    // Rounded up, so a partial token lost is still covered, but never more
+    // than the project has left to lose
+    let tokens_lost = u64::try_from(
+        (co2e_lost.0 as u128 * params.tokens_per_ton as u128).div_ceil(Tonnes::SCALE as u128),
+    )
+    .map_err(|_| ErrorCode::ArithmeticOverflow)?
+    .min(project.credits_issued.saturating_sub(project.tokens_reversed));
+    let buffer_cancelled = tokens_lost
+        .min(project.buffer_balance)
+        .min(ctx.accounts.buffer_pool.amount);
+    let liability = if intentional {
+        tokens_lost
+    } else {
+        tokens_lost - buffer_cancelled
+    };
+
+    if buffer_cancelled > 0 {
+        let registry = &ctx.accounts.registry;
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.carbon_token_mint.to_account_info(),
+            from: ctx.accounts.buffer_pool.to_account_info(),
+            authority: registry.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                &[&[b"registry_v3".as_ref(), &[registry.bump]]],
+            ),
+            buffer_cancelled,
+        )?;
+    }
+
+    let project = &mut ctx.accounts.project;
+    if project.verification_status.is_issuing() {
+        apply_transition(
+            project,
+            ProjectTransition::Suspend,
+            ctx.accounts.auditor.key(),
+            Some(evidence_cid.clone()),
+        )?;
+    }
+
+    let index = project.reversal_count;
+    project.reversal_count += 1;
+    project.tokens_reversed += tokens_lost;
+    project.buffer_balance -= buffer_cancelled;
+    project.reversal_liability = project
+        .reversal_liability
+        .checked_add(liability)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let reversal = &mut ctx.accounts.reversal;
+    reversal.project = project.key();
+    reversal.index = index;
+    reversal.evidence_cid = evidence_cid;
+    reversal.affected_area = affected_area;
+    reversal.co2e_lost = co2e_lost;
+    reversal.intentional = intentional;
+    reversal.tokens_lost = tokens_lost;
+    reversal.buffer_cancelled = buffer_cancelled;
+    reversal.liability = liability;
+    reversal.reported_by = ctx.accounts.auditor.key();
+    reversal.reported_at = Clock::get()?.unix_timestamp;
+    reversal.bump = ctx.bumps.reversal;
+
+    emit!(ReversalReported {
+        project: project.key(),
+        reversal: reversal.key(),
+        index,
+        co2e_lost,
+        intentional,
+        buffer_cancelled,
+        liability,
+        reversal_liability: project.reversal_liability,
+    });
+    msg!(
+        "Project {} reversal #{}: {} tCO2e lost, {} tokens cancelled from the buffer, {} owed",
+        project.project_id,
+        index,
+        co2e_lost,
+        buffer_cancelled,
+        liability
+    );
+
+    Ok(())
+}
+
+/// Owner pays off reversal liability by returning credits to the buffer
+/// pool. Minting resumes once nothing is owed; a suspended project still
+/// has to be reinstated.
+pub fn compensate_reversal(ctx: Context<CompensateReversal>, amount: u64) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Transfers)?;
+    require!(
+        amount <= ctx.accounts.project.reversal_liability,
+        ErrorCode::ExceedsReversalLiability
+    );
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.buffer_pool.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+        amount,
+    )?;
+
+    let project = &mut ctx.accounts.project;
+    project.reversal_liability -= amount;
+    project.buffer_balance += amount;
+
+    emit!(ReversalCompensated {
+        project: project.key(),
+        amount,
+        reversal_liability: project.reversal_liability,
+    });
+    msg!(
+        "Project {}: {} tokens returned to the buffer pool, {} still owed",
+        project.project_id,
+        amount,
+        project.reversal_liability
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::{
+    access::{execute_admin_proposal, has_permission},
+    validation::validate_authority,
+};
+use crate::instructions::{contexts::*, errors::ErrorCode};
+
+pub fn grant_role(ctx: Context<GrantRole>, holder: Pubkey, role: Role) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let authority = &ctx.accounts.authority;
+
+    // Only the registry admin can hand out admin rights; delegated admins
+    // can manage every other role.
+    if registry.multisig_enabled {
+        execute_admin_proposal(
+            authority,
+            registry,
+            ctx.accounts.proposal.as_mut(),
+            &AdminAction::GrantRole { holder, role },
+        )?;
+    } else if role == Role::Admin {
+        validate_authority(authority, registry)?;
+    } else {
+        require!(
+            has_permission(authority, Role::Admin, registry, ctx.accounts.authority_role.as_deref()),
+            ErrorCode::MissingRole
+        );
+    }
+
+    let assignment = &mut ctx.accounts.role_assignment;
+    assignment.holder = holder;
+    assignment.role = role;
+    assignment.granted_by = authority.key();
+    assignment.granted_at = Clock::get()?.unix_timestamp;
+    assignment.bump = ctx.bumps.role_assignment;
+
+    msg!("Granted {:?} role to {}", role, holder);
+
+    Ok(())
+}
+
+pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let authority = &ctx.accounts.authority;
+    let assignment = &ctx.accounts.role_assignment;
+
+    if registry.multisig_enabled {
+        execute_admin_proposal(
+            authority,
+            registry,
+            ctx.accounts.proposal.as_mut(),
+            &AdminAction::RevokeRole {
+                holder: assignment.holder,
+                role: assignment.role,
+            },
+        )?;
+    } else if assignment.role == Role::Admin {
+        validate_authority(authority, registry)?;
+    } else {
+        require!(
+            has_permission(authority, Role::Admin, registry, ctx.accounts.authority_role.as_deref()),
+            ErrorCode::MissingRole
+        );
+    }
+
+    msg!("Revoked {:?} role from {}", assignment.role, assignment.holder);
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use crate::models::*;
+use crate::fixed::Degrees;
+use crate::geo::{covering_cells, rings_overlap};
+use crate::instructions::{errors::ErrorCode, events::GeoCellsClaimed, resize::resize_account};
+
+/// Claims the spatial index cells covering `ring` for `project`, rejecting
+/// the boundary if it overlaps any other project already in those cells.
+/// When a boundary moves, `previous_ring` is the old one and the project is
+/// released from the cells only it covered. The cell limits come from
+/// `params`.
+///
+/// `accounts` holds the `GeoCell` PDAs in `covering_cells` order, then the
+/// released cells in the old ring's `covering_cells` order, followed by
+/// every other project listed in the claimed cells. Listed projects that
+/// have since been closed are dropped from the cell.
+pub(crate) fn claim_geo_cells<'info>(
+    project: Pubkey,
+    ring: &[[Degrees; 2]],
+    previous_ring: Option<&[[Degrees; 2]]>,
+    params: &ProtocolParams,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let cells = covering_cells(ring);
+    require!(
+        cells.len() <= params.max_geo_cells_per_project as usize,
+        ErrorCode::TooManyGeoCells
+    );
+    let released: Vec<String> = previous_ring
+        .map(covering_cells)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|geohash| !cells.contains(geohash))
+        .collect();
+    require!(
+        accounts.len() >= cells.len() + released.len(),
+        ErrorCode::InvalidGeoCell
+    );
+    let (cell_accounts, rest) = accounts.split_at(cells.len());
+    let (released_accounts, candidates) = rest.split_at(released.len());
+
+    let mut checked: Vec<Pubkey> = Vec::new();
+    for (geohash, cell_info) in cells.iter().zip(cell_accounts) {
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"geo_cell", geohash.as_bytes()], &crate::ID);
+        require_keys_eq!(cell_info.key(), address, ErrorCode::InvalidGeoCell);
+
+        let mut cell = if cell_info.owner == &crate::ID {
+            GeoCell::try_deserialize(&mut &cell_info.try_borrow_data()?[..])?
+        } else {
+            let cell = GeoCell {
+                geohash: geohash.clone(),
+                projects: Vec::new(),
+                bump,
+            };
+            // Room for this project's entry; later ones grow the cell
+            create_geo_cell(cell_info, geohash, bump, cell.space() + 32, payer, system_program)?;
+            cell
+        };
+
+        let mut live = Vec::with_capacity(cell.projects.len() + 1);
+        for other in cell.projects {
+            if other == project {
+                continue;
+            }
+            let candidate = candidates
+                .iter()
+                .find(|info| info.key() == other)
+                .ok_or(ErrorCode::MissingOverlapCandidate)?;
+            if candidate.owner != &crate::ID {
+                // Project account was closed
+                continue;
+            }
+            if !checked.contains(&other) {
+                let other_project = Project::try_deserialize(&mut &candidate.try_borrow_data()?[..])?;
+                require!(
+                    !rings_overlap(ring, &other_project.location.polygon_coordinates),
+                    ErrorCode::SpatialOverlap
+                );
+                checked.push(other);
+            }
+            live.push(other);
+        }
+        require!(
+            live.len() < params.max_projects_per_geo_cell as usize,
+            ErrorCode::GeoCellFull
+        );
+        live.push(project);
+        cell.projects = live;
+
+        // Cells only grow, keeping room freed by released projects
+        if cell.space() > cell_info.data_len() {
+            resize_account(cell_info, payer, system_program, cell.space())?;
+        }
+        cell.try_serialize(&mut &mut cell_info.try_borrow_mut_data()?[..])?;
+    }
+
+    for (geohash, cell_info) in released.iter().zip(released_accounts) {
+        let (address, _) =
+            Pubkey::find_program_address(&[b"geo_cell", geohash.as_bytes()], &crate::ID);
+        require_keys_eq!(cell_info.key(), address, ErrorCode::InvalidGeoCell);
+        if cell_info.owner != &crate::ID {
+            continue;
+        }
+
+        let mut cell = GeoCell::try_deserialize(&mut &cell_info.try_borrow_data()?[..])?;
+        cell.projects.retain(|other| *other != project);
+        cell.try_serialize(&mut &mut cell_info.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(GeoCellsClaimed { project, cells });
+
+    Ok(())
+}
+
+// Creates a cell PDA, tolerating lamports already sent to its address
+fn create_geo_cell<'info>(
+    cell_info: &AccountInfo<'info>,
+    geohash: &str,
+    bump: u8,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let seeds: &[&[u8]] = &[b"geo_cell", geohash.as_bytes(), &[bump]];
+    let program = system_program.to_account_info();
+
+    if cell_info.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                program,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: cell_info.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(cell_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                program.clone(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: cell_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            program.clone(),
+            Allocate {
+                account_to_allocate: cell_info.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            program,
+            Assign {
+                account_to_assign: cell_info.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
@@ -1,8 +1,20 @@
 use anchor_lang::prelude::*;
 use crate::models::*;
-use crate::instructions::contexts::*;
+use crate::fixed::*;
+use crate::auth_utils::{access::has_permission, validation::{validate_cid, validate_len, validate_max_entries}};
+use crate::instructions::{contexts::*, errors::ErrorCode, resize::fit_account_space};
 
 pub fn track_impact(ctx: Context<TrackImpact>, data: ImpactData) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.authority,
+            Role::DataProvider,
+            &ctx.accounts.registry,
+            ctx.accounts.authority_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
     msg!("CO₂ absorbed: {}", data.co2_absorbed);
     msg!("Biodiversity Index: {}", data.biodiversity_index);
     Ok(())
@@ -10,22 +22,40 @@ pub fn track_impact(ctx: Context<TrackImpact>, data: ImpactData) -> Result<()> {
 
 pub fn generate_impact_report(
     ctx: Context<GenerateImpactReport>,
-    project_id: String,
-    reporting_period_end: i64,
+    _project_id: String,
+    _reporting_period_end: i64,
     report_data: ImpactReportData,
 ) -> Result<()> {
+    require!(
+        has_permission(
+            &ctx.accounts.report_generator,
+            Role::Auditor,
+            &ctx.accounts.registry,
+            ctx.accounts.report_generator_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
+    validate_report_data(&report_data, &ctx.accounts.config.params)?;
+
     let report = &mut ctx.accounts.impact_report;
     let project = &mut ctx.accounts.project;
 
-    report.project_id = report_data.project_id;
+    report.version = ImpactReport::VERSION;
+    report.project_id = project.project_id.clone();
     report.reporting_period_start = report_data.reporting_period_start;
     report.reporting_period_end = report_data.reporting_period_end;
     report.carbon_sequestered = report_data.carbon_sequestered;
-    report.ecosystem_health_improvement = 
-        project.current_ecosystem_health - project.baseline_ecosystem_health;
-    report.biodiversity_increase = 
-        (project.species_count_current as f64 - project.species_count_baseline as f64) /
-        project.species_count_baseline as f64 * 100.0;
+    report.ecosystem_health_improvement = project
+        .current_ecosystem_health
+        .checked_sub(project.baseline_ecosystem_health)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    // Without a baseline count there is nothing to compare against
+    report.biodiversity_increase = match project.species_count_baseline {
+        0 => BasisPoints::ZERO,
+        baseline => BasisPoints::change(baseline as u64, project.species_count_current as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?,
+    };
     report.community_benefits = report_data.community_benefits;
     report.economic_impact = report_data.economic_impact;
     report.sdg_contributions = report_data.sdg_contributions;
@@ -38,18 +68,47 @@ pub fn generate_impact_report(
     msg!("Carbon sequestered: {} tons", report.carbon_sequestered);
     msg!("Ecosystem health improvement: {}%", report.ecosystem_health_improvement);
 
+    fit_account_space(
+        &ctx.accounts.impact_report,
+        &ctx.accounts.report_generator,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Length and count limits for a new impact report.
+pub(crate) fn validate_report_data(report_data: &ImpactReportData, params: &ProtocolParams) -> Result<()> {
+    validate_cid(&report_data.verification_report_cid, params.max_cid_len)?;
+    validate_max_entries(
+        report_data.community_benefits.len(),
+        ImpactReport::MAX_COMMUNITY_BENEFITS,
+    )?;
+    for benefit in &report_data.community_benefits {
+        validate_len(&benefit.benefit_type, params.max_name_len)?;
+    }
+    validate_max_entries(
+        report_data.sdg_contributions.len(),
+        ImpactReport::MAX_SDG_CONTRIBUTIONS,
+    )?;
     Ok(())
 }
 
 // Legacy ImpactData structure for backwards compatibility
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ImpactData {
-    pub co2_absorbed: f64,
-    pub biodiversity_index: f64,
+    pub co2_absorbed: Tonnes,
+    pub biodiversity_index: Measure,
 }
 
 // TrackImpact context (simple version)
 #[derive(Accounts)]
 pub struct TrackImpact<'info> {
+    #[account(
+        seeds = [b"registry_v3"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, GlobalRegistry>,
+
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
     pub authority: Signer<'info>,
 }
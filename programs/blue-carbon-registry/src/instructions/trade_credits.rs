@@ -1,8 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
+use crate::models::*;
+use crate::auth_utils::{access::has_permission, validation::require_not_paused};
 use crate::instructions::{contexts::*, errors::ErrorCode};
 
 pub fn trade_credits(ctx: Context<TradeCredits>, amount: u64) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Marketplace)?;
+    require!(
+        has_permission(
+            &ctx.accounts.authority,
+            Role::MarketplaceOperator,
+            &ctx.accounts.registry,
+            ctx.accounts.authority_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+
     // This is a placeholder that just uses the internal project.credits counter
     // In a real implementation, this would transfer SPL tokens
     msg!("Trading {} credits (internal counter)", amount);
@@ -10,6 +23,8 @@ pub fn trade_credits(ctx: Context<TradeCredits>, amount: u64) -> Result<()> {
 }
 
 pub fn transfer_credits(ctx: Context<TransferCredits>, amount: u64) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Transfers)?;
+
     let cpi_accounts = Transfer {
         from: ctx.accounts.from_account.to_account_info(),
         to: ctx.accounts.to_account.to_account_info(),
@@ -26,6 +41,8 @@ pub fn transfer_credits(ctx: Context<TransferCredits>, amount: u64) -> Result<()
 }
 
 pub fn retire_credits(ctx: Context<RetireCredits>, amount: u64) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Transfers)?;
+
     let cpi_accounts = Transfer {
         from: ctx.accounts.from_account.to_account_info(),
         to: ctx.accounts.retirement_account.to_account_info(),
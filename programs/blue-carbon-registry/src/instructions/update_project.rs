@@ -0,0 +1,301 @@
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::auth_utils::validation::{
+    require_not_paused, validate_carbon_science, validate_cid, validate_len, validate_location,
+    validate_max_entries, validate_site_eligibility,
+};
+use crate::instructions::{
+    contexts::*, errors::ErrorCode, events::ProjectAmended, lifecycle::apply_transition,
+    resize::fit_account_space, spatial_index::claim_geo_cells,
+};
+
+/// Applies a partial edit to a project and records it as a ProjectAmendment.
+///
+/// Pending and rejected projects can be edited freely. A project under
+/// review keeps its carbon science fields fixed until the verifier decides.
+/// Once a project has been verified, changing any carbon science field sends
+/// it back to UnderReview so it must be re-verified before minting again.
+/// Suspended and expired projects cannot be edited.
+///
+/// Moving the project boundary claims spatial index cells for the new
+/// polygon and releases the old cells it no longer covers; pass the new
+/// cells, the released cells and the candidate project accounts as
+/// remaining accounts.
+///
+/// Sites onboarded under a programme cannot change the fields they inherit
+/// from it, and changes to their boundary, area, uncertainty or leakage are
+/// checked against the programme's eligibility criteria; pass the programme
+/// account for those.
+///
+/// The project account is resized to its new contents, so growing a list
+/// charges the owner rent and shrinking one refunds it.
+pub fn update_project<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateProject<'info>>,
+    update: ProjectUpdate,
+) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Registration)?;
+    validate_update(&update, &ctx.accounts.config.params)?;
+
+    let project = &mut ctx.accounts.project;
+    if update.location.is_some() || update.area_hectares.is_some() {
+        validate_location(
+            update.location.as_ref().unwrap_or(&project.location),
+            update.area_hectares.unwrap_or(project.area_hectares),
+        )?;
+    }
+    if let Some(programme_key) = project.programme {
+        check_programme_site_update(
+            project,
+            programme_key,
+            ctx.accounts.programme.as_mut(),
+            &update,
+        )?;
+    }
+    if let Some(location) = &update.location {
+        claim_geo_cells(
+            project.key(),
+            &location.polygon_coordinates,
+            Some(&project.location.polygon_coordinates),
+            &ctx.accounts.config.params,
+            &ctx.accounts.owner,
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    let previous_status = project.verification_status.clone();
+    let carbon_science_changed = touches_carbon_science(&update);
+
+    match previous_status {
+        VerificationStatus::Pending | VerificationStatus::Rejected => {}
+        // The verifier is assessing the current figures
+        VerificationStatus::UnderReview => {
+            require!(!carbon_science_changed, ErrorCode::ProjectNotEditable);
+        }
+        VerificationStatus::Verified | VerificationStatus::Monitoring => {
+            if carbon_science_changed {
+                apply_transition(
+                    project,
+                    ProjectTransition::Reopen,
+                    ctx.accounts.owner.key(),
+                    None,
+                )?;
+            }
+        }
+        VerificationStatus::Expired | VerificationStatus::Suspended => {
+            return err!(ErrorCode::ProjectNotEditable)
+        }
+    }
+
+    apply_update(project, update.clone());
+
+    let amendment = &mut ctx.accounts.amendment;
+    amendment.project = project.key();
+    amendment.index = project.amendment_count;
+    amendment.editor = ctx.accounts.owner.key();
+    amendment.timestamp = Clock::get()?.unix_timestamp;
+    amendment.previous_status = previous_status.clone();
+    amendment.new_status = project.verification_status.clone();
+    amendment.carbon_science_changed = carbon_science_changed;
+    amendment.update = update;
+    amendment.bump = ctx.bumps.amendment;
+
+    project.amendment_count += 1;
+
+    emit!(ProjectAmended {
+        project: project.key(),
+        amendment: amendment.key(),
+        index: amendment.index,
+        editor: amendment.editor,
+        carbon_science_changed,
+        previous_status,
+        new_status: project.verification_status.clone(),
+    });
+    msg!("Project {} amended (#{})", project.project_id, amendment.index);
+    if carbon_science_changed && amendment.previous_status != amendment.new_status {
+        msg!("Carbon science changed; project returned to UnderReview");
+    }
+
+    fit_account_space(
+        &ctx.accounts.project,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+    )
+}
+
+fn touches_carbon_science(update: &ProjectUpdate) -> bool {
+    update.carbon_tons_estimated.is_some()
+        || update.ecosystem_type.is_some()
+        || update.location.is_some()
+        || update.area_hectares.is_some()
+        || update.above_ground_biomass.is_some()
+        || update.below_ground_biomass.is_some()
+        || update.soil_carbon_0_30cm.is_some()
+        || update.soil_carbon_30_100cm.is_some()
+        || update.sequestration_rate_annual.is_some()
+        || update.measurement_methodology.is_some()
+        || update.uncertainty_percentage.is_some()
+        || update.vcs_methodology.is_some()
+        || update.leakage_assessment.is_some()
+}
+
+fn touches_programme_design(update: &ProjectUpdate) -> bool {
+    update.ecosystem_type.is_some()
+        || update.vcs_methodology.is_some()
+        || update.measurement_methodology.is_some()
+        || update.monitoring_plan_cid.is_some()
+        || update.additionality_proof_cid.is_some()
+        || update.permanence_guarantee_years.is_some()
+}
+
+/// Rejects edits to fields a programme site inherits, and re-checks the
+/// site against the programme's eligibility criteria when the update
+/// touches what they cover. Keeps the programme's total area in step.
+fn check_programme_site_update(
+    project: &Project,
+    programme_key: Pubkey,
+    programme: Option<&mut Account<Programme>>,
+    update: &ProjectUpdate,
+) -> Result<()> {
+    require!(!touches_programme_design(update), ErrorCode::InheritedFromProgramme);
+    if update.location.is_none()
+        && update.area_hectares.is_none()
+        && update.uncertainty_percentage.is_none()
+        && update.leakage_assessment.is_none()
+    {
+        return Ok(());
+    }
+
+    let programme = programme.ok_or(ErrorCode::ProgrammeMismatch)?;
+    require_keys_eq!(programme.key(), programme_key, ErrorCode::ProgrammeMismatch);
+
+    let area_hectares = update.area_hectares.unwrap_or(project.area_hectares);
+    validate_site_eligibility(
+        &programme.eligibility,
+        update.location.as_ref().unwrap_or(&project.location),
+        area_hectares,
+        update.uncertainty_percentage.unwrap_or(project.uncertainty_percentage),
+        update.leakage_assessment.unwrap_or(project.leakage_assessment),
+    )?;
+
+    programme.total_area_hectares = programme
+        .total_area_hectares
+        .checked_sub(project.area_hectares)
+        .and_then(|total| total.checked_add(area_hectares))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+fn validate_update(update: &ProjectUpdate, params: &ProtocolParams) -> Result<()> {
+    let has_other_changes = update.ipfs_cid.is_some()
+        || update.species_composition.is_some()
+        || update.biodiversity_index.is_some()
+        || update.additionality_proof_cid.is_some()
+        || update.permanence_guarantee_years.is_some()
+        || update.monitoring_plan_cid.is_some()
+        || update.co_benefits.is_some()
+        || update.price_per_ton.is_some();
+    require!(
+        has_other_changes || touches_carbon_science(update),
+        ErrorCode::EmptyProjectUpdate
+    );
+
+    for cid in [
+        &update.ipfs_cid,
+        &update.additionality_proof_cid,
+        &update.monitoring_plan_cid,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        validate_cid(cid, params.max_cid_len)?;
+    }
+    for methodology in [&update.measurement_methodology, &update.vcs_methodology]
+        .into_iter()
+        .flatten()
+    {
+        validate_len(methodology, params.max_methodology_len)?;
+    }
+    if let Some(location) = &update.location {
+        validate_len(&location.region_name, params.max_name_len)?;
+    }
+    validate_carbon_science(update.uncertainty_percentage, update.leakage_assessment)?;
+    if let Some(species_composition) = &update.species_composition {
+        validate_max_entries(species_composition.len(), Project::MAX_SPECIES)?;
+        for species in species_composition {
+            validate_len(species, params.max_name_len)?;
+        }
+    }
+    if let Some(co_benefits) = &update.co_benefits {
+        validate_max_entries(co_benefits.len(), Project::MAX_CO_BENEFITS)?;
+    }
+
+    Ok(())
+}
+
+fn apply_update(project: &mut Project, update: ProjectUpdate) {
+    if let Some(value) = update.ipfs_cid {
+        project.ipfs_cid = value;
+    }
+    if let Some(value) = update.species_composition {
+        project.species_composition = value;
+    }
+    if let Some(value) = update.biodiversity_index {
+        project.biodiversity_index = value;
+    }
+    if let Some(value) = update.additionality_proof_cid {
+        project.additionality_proof_cid = value;
+    }
+    if let Some(value) = update.permanence_guarantee_years {
+        project.permanence_guarantee_years = value;
+    }
+    if let Some(value) = update.monitoring_plan_cid {
+        project.monitoring_plan_cid = value;
+    }
+    if let Some(value) = update.co_benefits {
+        project.co_benefits = value;
+    }
+    if let Some(value) = update.price_per_ton {
+        project.price_per_ton = value;
+    }
+    if let Some(value) = update.carbon_tons_estimated {
+        project.carbon_tons_estimated = value;
+    }
+    if let Some(value) = update.ecosystem_type {
+        project.ecosystem_type = value;
+    }
+    if let Some(value) = update.location {
+        project.location = value;
+    }
+    if let Some(value) = update.area_hectares {
+        project.area_hectares = value;
+    }
+    if let Some(value) = update.above_ground_biomass {
+        project.above_ground_biomass = value;
+    }
+    if let Some(value) = update.below_ground_biomass {
+        project.below_ground_biomass = value;
+    }
+    if let Some(value) = update.soil_carbon_0_30cm {
+        project.soil_carbon_0_30cm = value;
+    }
+    if let Some(value) = update.soil_carbon_30_100cm {
+        project.soil_carbon_30_100cm = value;
+    }
+    if let Some(value) = update.sequestration_rate_annual {
+        project.sequestration_rate_annual = value;
+    }
+    if let Some(value) = update.measurement_methodology {
+        project.measurement_methodology = value;
+    }
+    if let Some(value) = update.uncertainty_percentage {
+        project.uncertainty_percentage = value;
+    }
+    if let Some(value) = update.vcs_methodology {
+        project.vcs_methodology = value;
+    }
+    if let Some(value) = update.leakage_assessment {
+        project.leakage_assessment = value;
+    }
+}
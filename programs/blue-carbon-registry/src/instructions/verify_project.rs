@@ -1,19 +1,62 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use crate::models::*;
-use crate::instructions::{contexts::*, errors::ErrorCode};
+use crate::carbon::{self, CarbonEstimate, CarbonInputs};
+use crate::fixed::Hectares;
+use crate::auth_utils::{
+    access::{execute_admin_proposal, has_permission},
+    validation::{require_not_paused, validate_cid, validate_len, validate_max_entries},
+};
+use crate::instructions::{
+    contexts::*, documents::record_document, errors::ErrorCode,
+    baseline::lock_baseline,
+    lifecycle::{apply_transition, authorize_transition, start_crediting_period},
+    methodology::check_project_methodology,
+    resize::fit_account_space,
+};
 
+/// Verifier approves a project under review, capping verified tons at its
+/// creditable carbon. In multisig mode `admin` is a member executing an
+/// approved VerifyProject proposal instead.
 pub fn verify_project(
     ctx: Context<VerifyProject>,
     verified_carbon_tons: u64,
 ) -> Result<()> {
-    let project = &mut ctx.accounts.project;
-    
-    require!(
-        project.verification_status == VerificationStatus::Pending,
-        ErrorCode::ProjectAlreadyProcessed
-    );
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
 
-    project.verification_status = VerificationStatus::Verified;
+    if ctx.accounts.registry.multisig_enabled {
+        let action = AdminAction::VerifyProject {
+            project: ctx.accounts.project.key(),
+            verified_carbon_tons,
+        };
+        execute_admin_proposal(
+            &ctx.accounts.admin,
+            &ctx.accounts.registry,
+            ctx.accounts.proposal.as_mut(),
+            &action,
+        )?;
+    } else {
+        authorize_transition(
+            ProjectTransition::Verify,
+            &ctx.accounts.admin,
+            &ctx.accounts.project,
+            &ctx.accounts.registry,
+            ctx.accounts.admin_role.as_deref(),
+        )?;
+    }
+
+    let methodology = check_project_methodology(&ctx.accounts.project, ctx.accounts.methodology.as_ref())?;
+    require_within_creditable_ceiling(&ctx.accounts.project, methodology, verified_carbon_tons)?;
+
+    let project = &mut ctx.accounts.project;
+    apply_transition(
+        project,
+        ProjectTransition::Verify,
+        ctx.accounts.admin.key(),
+        None,
+    )?;
+    start_crediting_period(project, ctx.accounts.config.params.crediting_period_years)?;
+    lock_baseline(project)?;
     project.carbon_tons_estimated = verified_carbon_tons;
 
     msg!("Project {} verified successfully!", project.project_id);
@@ -27,46 +70,171 @@ pub fn multi_party_verify_project(
     verified_carbon_tons: u64,
     quality_rating: u8,
     verification_report_cid: String,
+    verification_report_hash: [u8; 32],
 ) -> Result<()> {
-    let project = &mut ctx.accounts.project;
-    let verifier = &mut ctx.accounts.verifier;
-    
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+
     require!(
-        project.verification_status == VerificationStatus::Pending ||
-        project.verification_status == VerificationStatus::UnderReview,
-        ErrorCode::ProjectAlreadyProcessed
+        has_permission(
+            &ctx.accounts.verifier_authority,
+            Role::Verifier,
+            &ctx.accounts.registry,
+            ctx.accounts.verifier_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
     );
+    require_multisig_approval(
+        &ctx.accounts.registry,
+        ctx.accounts.executor.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        &AdminAction::MultiPartyVerifyProject {
+            project: ctx.accounts.project.key(),
+            verifier_authority: ctx.accounts.verifier_authority.key(),
+            verified_carbon_tons,
+            quality_rating,
+            verification_report_hash,
+        },
+    )?;
+
+    let project = &mut ctx.accounts.project;
+    let verifier = &mut ctx.accounts.verifier;
 
+    let params = &ctx.accounts.config.params;
     require!(verifier.is_active, ErrorCode::VerifierNotActive);
-    require!(quality_rating >= 1 && quality_rating <= 5, ErrorCode::InvalidQualityRating);
+    require!(
+        (params.min_quality_rating..=params.max_quality_rating).contains(&quality_rating),
+        ErrorCode::InvalidQualityRating
+    );
+    validate_cid(&verification_report_cid, params.max_cid_len)?;
+    let methodology = check_project_methodology(project, ctx.accounts.methodology.as_ref())?;
+    require_within_creditable_ceiling(project, methodology, verified_carbon_tons)?;
 
     // Update project verification
-    project.verification_status = VerificationStatus::Verified;
+    apply_transition(
+        project,
+        ProjectTransition::Verify,
+        ctx.accounts.verifier_authority.key(),
+        None,
+    )?;
+    start_crediting_period(project, params.crediting_period_years)?;
+    lock_baseline(project)?;
     project.carbon_tons_estimated = verified_carbon_tons;
     project.quality_rating = quality_rating;
     project.available_quantity = verified_carbon_tons;
 
     // Update verifier stats
     verifier.verification_count += 1;
-    verifier.reputation_score += 10; // Reward for verification
+    verifier.reputation_score += params.verification_reputation_reward;
 
     msg!("Project {} verified by {:?}", project.project_id, verifier.verifier_type);
     msg!("Verified carbon tons: {}", verified_carbon_tons);
-    msg!("Quality rating: {}/5", quality_rating);
+    msg!("Quality rating: {}/{}", quality_rating, params.max_quality_rating);
+
+    record_document(
+        &mut ctx.accounts.report_document,
+        project.key(),
+        DocumentKind::VerificationReport,
+        verification_report_cid,
+        verification_report_hash,
+        ctx.accounts.verifier_authority.key(),
+        None,
+        ctx.bumps.report_document,
+    )?;
 
     Ok(())
 }
 
+/// Runs the carbon calculation verification caps verified tons with and
+/// returns it, so clients can simulate this to preview the ceiling.
+pub fn estimate_creditable_carbon(ctx: Context<EstimateCreditableCarbon>) -> Result<CarbonEstimate> {
+    let project = &ctx.accounts.project;
+    let methodology = check_project_methodology(project, ctx.accounts.methodology.as_ref())?;
+    let estimate = carbon::estimate(&CarbonInputs::from_project(project, methodology))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!(
+        "Project {}: {} tCO2e gross, {} tCO2e creditable",
+        project.project_id,
+        estimate.gross_co2e,
+        estimate.creditable
+    );
+
+    Ok(estimate)
+}
+
+/// Rejects verified tons above what the project's own measurements support
+/// after leakage and uncertainty deductions. Projects registered through
+/// register_project carry no site data to derive a ceiling from and are
+/// not capped.
+fn require_within_creditable_ceiling(
+    project: &Project,
+    methodology: Option<&Methodology>,
+    verified_carbon_tons: u64,
+) -> Result<()> {
+    if project.area_hectares == Hectares::ZERO {
+        return Ok(());
+    }
+    let estimate = carbon::estimate(&CarbonInputs::from_project(project, methodology))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    require!(
+        verified_carbon_tons <= estimate.creditable_tons(),
+        ErrorCode::ExceedsCreditableCarbon
+    );
+    Ok(())
+}
+
+/// While multisig mode is enabled, holding the Verifier role is not enough
+/// on its own: a multisig member co-signs as `executor` and executes an
+/// approved proposal for exactly `action`.
+fn require_multisig_approval(
+    registry: &GlobalRegistry,
+    executor: Option<&Signer>,
+    proposal: Option<&mut Account<AdminProposal>>,
+    action: &AdminAction,
+) -> Result<()> {
+    if !registry.multisig_enabled {
+        return Ok(());
+    }
+    let executor = executor.ok_or(ErrorCode::MultisigApprovalRequired)?;
+    execute_admin_proposal(executor, registry, proposal, action)
+}
+
 pub fn register_verifier(
     ctx: Context<RegisterVerifier>,
     verifier_data: VerifierData,
 ) -> Result<()> {
+    require_not_paused(&ctx.accounts.registry, Subsystem::Verification)?;
+
+    require!(
+        has_permission(
+            &ctx.accounts.verifier_authority,
+            Role::Verifier,
+            &ctx.accounts.registry,
+            ctx.accounts.verifier_role.as_deref(),
+        ),
+        ErrorCode::MissingRole
+    );
+    let data_hash = hash(&verifier_data.try_to_vec()?).to_bytes();
+    require_multisig_approval(
+        &ctx.accounts.registry,
+        ctx.accounts.executor.as_ref(),
+        ctx.accounts.proposal.as_mut(),
+        &AdminAction::AccreditVerifier {
+            verifier_authority: ctx.accounts.verifier_authority.key(),
+            data_hash,
+        },
+    )?;
+
+    let params = &ctx.accounts.config.params;
+    validate_verifier_profile(&verifier_data.credentials, &verifier_data.specializations, params)?;
+
     let verifier = &mut ctx.accounts.verifier;
 
+    verifier.version = VerificationNode::VERSION;
     verifier.verifier_pubkey = ctx.accounts.verifier_authority.key();
     verifier.verifier_type = verifier_data.verifier_type;
     verifier.credentials = verifier_data.credentials;
-    verifier.reputation_score = 100; // Starting score
+    verifier.reputation_score = params.starting_verifier_reputation;
     verifier.verification_count = 0;
     verifier.is_active = true;
     verifier.registration_date = Clock::get()?.unix_timestamp;
@@ -74,5 +242,44 @@ pub fn register_verifier(
 
     msg!("Verifier registered: {:?}", verifier.verifier_type);
 
+    fit_account_space(
+        &ctx.accounts.verifier,
+        &ctx.accounts.verifier_authority,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Replaces a verifier's credentials and specializations, resizing the
+/// account to the new lists.
+pub fn update_verifier_profile(
+    ctx: Context<UpdateVerifierProfile>,
+    credentials: Vec<String>,
+    specializations: Vec<EcosystemType>,
+) -> Result<()> {
+    validate_verifier_profile(&credentials, &specializations, &ctx.accounts.config.params)?;
+
+    let verifier = &mut ctx.accounts.verifier;
+    verifier.credentials = credentials;
+    verifier.specializations = specializations;
+
+    msg!("Verifier profile updated: {}", verifier.verifier_pubkey);
+
+    fit_account_space(
+        &ctx.accounts.verifier,
+        &ctx.accounts.verifier_authority,
+        &ctx.accounts.system_program,
+    )
+}
+
+pub(crate) fn validate_verifier_profile(
+    credentials: &[String],
+    specializations: &[EcosystemType],
+    params: &ProtocolParams,
+) -> Result<()> {
+    validate_max_entries(credentials.len(), VerificationNode::MAX_CREDENTIALS)?;
+    validate_max_entries(specializations.len(), VerificationNode::MAX_SPECIALIZATIONS)?;
+    for credential in credentials {
+        validate_len(credential, params.max_name_len)?;
+    }
     Ok(())
 }
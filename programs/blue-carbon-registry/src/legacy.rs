@@ -0,0 +1,573 @@
+//! Frozen account layouts from earlier program versions.
+//!
+//! `V0` structs are the layouts deployed before accounts carried a `version`
+//! byte. They are stored under Anchor's default discriminator, while every
+//! versioned layout has its own (see `Versioned`), so `migrate_*` can tell
+//! the two apart without guessing from the data. Never change a layout in
+//! here; when a versioned layout changes after it has been deployed, add a
+//! `V<n>` struct and dispatch on the version byte that follows the
+//! discriminator.
+//!
+//! The frozen layouts are only ever read on chain; tests also serialize
+//! them to build legacy accounts to migrate.
+
+use anchor_lang::prelude::*;
+use crate::models::*;
+use crate::fixed::*;
+
+/// Decodes an account body (without discriminator) stored in the baseline
+/// layout of `Self`.
+pub trait FromLegacy: Sized {
+    /// sha256("account:<Name>")[..8], the discriminator of the `V0` layout
+    const LEGACY_DISCRIMINATOR: [u8; 8];
+
+    fn from_legacy(data: &[u8]) -> Option<Self>;
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct GlobalRegistryV0 {
+    pub total_credits_issued: u64,
+    pub total_projects: u64,
+    pub admin: Pubkey,
+    pub mint_authority: Pubkey,
+    pub carbon_token_mint: Pubkey,
+    pub bump: u8,
+    pub mint_authority_bump: u8,
+}
+
+impl From<GlobalRegistryV0> for GlobalRegistry {
+    fn from(legacy: GlobalRegistryV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            total_credits_issued: legacy.total_credits_issued,
+            total_projects: legacy.total_projects,
+            admin: legacy.admin,
+            mint_authority: legacy.mint_authority,
+            carbon_token_mint: legacy.carbon_token_mint,
+            bump: legacy.bump,
+            mint_authority_bump: legacy.mint_authority_bump,
+            pending_admin: None,
+            mint_authority_generation: 0,
+            multisig_enabled: false,
+            multisig_nonce: 0,
+            admin_proposal_count: 0,
+            paused_subsystems: 0,
+        }
+    }
+}
+
+/// A legacy float that is NaN, infinite or out of range for the fixed-point
+/// type that replaced it. Accounts holding one cannot be migrated.
+#[derive(Debug)]
+pub struct InvalidLegacyValue;
+
+fn fixed<T>(value: f64, convert: fn(f64) -> Option<T>) -> std::result::Result<T, InvalidLegacyValue> {
+    convert(value).ok_or(InvalidLegacyValue)
+}
+
+// Nested types as they were stored before fixed-point, shared by every
+// frozen layout below that embeds them
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct GeoLocationV0 {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub polygon_coordinates: Vec<[f64; 2]>,
+    pub country_code: String,
+    pub region_name: String,
+}
+
+impl TryFrom<GeoLocationV0> for GeoLocation {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: GeoLocationV0) -> std::result::Result<Self, Self::Error> {
+        let polygon_coordinates = legacy
+            .polygon_coordinates
+            .into_iter()
+            .map(|[lon, lat]| Ok([fixed(lon, Degrees::from_f64)?, fixed(lat, Degrees::from_f64)?]))
+            .collect::<std::result::Result<_, InvalidLegacyValue>>()?;
+        Ok(Self {
+            latitude: fixed(legacy.latitude, Degrees::from_f64)?,
+            longitude: fixed(legacy.longitude, Degrees::from_f64)?,
+            polygon_coordinates,
+            country_code: legacy.country_code,
+            region_name: legacy.region_name,
+        })
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct WaterQualityV0 {
+    pub ph_level: f64,
+    pub salinity: f64,
+    pub dissolved_oxygen: f64,
+    pub turbidity: f64,
+    pub nutrients: NutrientLevelsV0,
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct NutrientLevelsV0 {
+    pub nitrogen: f64,
+    pub phosphorus: f64,
+    pub potassium: f64,
+}
+
+impl TryFrom<WaterQualityV0> for WaterQuality {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: WaterQualityV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            ph_level: fixed(legacy.ph_level, Measure::from_f64)?,
+            salinity: fixed(legacy.salinity, Measure::from_f64)?,
+            dissolved_oxygen: fixed(legacy.dissolved_oxygen, Measure::from_f64)?,
+            turbidity: fixed(legacy.turbidity, Measure::from_f64)?,
+            nutrients: NutrientLevels {
+                nitrogen: fixed(legacy.nutrients.nitrogen, Measure::from_f64)?,
+                phosphorus: fixed(legacy.nutrients.phosphorus, Measure::from_f64)?,
+                potassium: fixed(legacy.nutrients.potassium, Measure::from_f64)?,
+            },
+        })
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct TideReadingV0 {
+    pub timestamp: i64,
+    pub tide_height: f64,
+    pub tide_type: String,
+}
+
+impl TryFrom<TideReadingV0> for TideReading {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: TideReadingV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            timestamp: legacy.timestamp,
+            tide_height: fixed(legacy.tide_height, Measure::from_f64)?,
+            tide_type: legacy.tide_type,
+        })
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct SensorReadingV0 {
+    pub sensor_id: String,
+    pub timestamp: i64,
+    pub co2_flux: f64,
+    pub soil_moisture: f64,
+    pub ph_level: f64,
+    pub temperature: f64,
+    pub humidity: f64,
+}
+
+impl TryFrom<SensorReadingV0> for SensorReading {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: SensorReadingV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            sensor_id: legacy.sensor_id,
+            timestamp: legacy.timestamp,
+            co2_flux: fixed(legacy.co2_flux, Measure::from_f64)?,
+            soil_moisture: fixed(legacy.soil_moisture, BasisPoints::from_f64)?,
+            ph_level: fixed(legacy.ph_level, Measure::from_f64)?,
+            temperature: fixed(legacy.temperature, Measure::from_f64)?,
+            humidity: fixed(legacy.humidity, BasisPoints::from_f64)?,
+        })
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct CommunityBenefitV0 {
+    pub benefit_type: String,
+    pub households_affected: u32,
+    pub jobs_created: u32,
+    pub income_increase_percentage: f64,
+    pub capacity_building_programs: u32,
+}
+
+impl TryFrom<CommunityBenefitV0> for CommunityBenefit {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: CommunityBenefitV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            benefit_type: legacy.benefit_type,
+            households_affected: legacy.households_affected,
+            jobs_created: legacy.jobs_created,
+            income_increase_percentage: fixed(legacy.income_increase_percentage, BasisPoints::from_f64)?,
+            capacity_building_programs: legacy.capacity_building_programs,
+        })
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct EconomicImpactV0 {
+    pub direct_revenue: u64,
+    pub indirect_benefits: u64,
+    pub cost_savings: u64,
+    pub roi_percentage: f64,
+    pub payback_period_years: f64,
+}
+
+impl TryFrom<EconomicImpactV0> for EconomicImpact {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: EconomicImpactV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            direct_revenue: legacy.direct_revenue,
+            indirect_benefits: legacy.indirect_benefits,
+            cost_savings: legacy.cost_savings,
+            roi_percentage: fixed(legacy.roi_percentage, BasisPoints::from_f64)?,
+            payback_period_years: fixed(legacy.payback_period_years, Measure::from_f64)?,
+        })
+    }
+}
+
+// Converts every element of a frozen Vec
+fn convert_all<T, U: TryFrom<T, Error = InvalidLegacyValue>>(
+    legacy: Vec<T>,
+) -> std::result::Result<Vec<U>, InvalidLegacyValue> {
+    legacy.into_iter().map(U::try_from).collect()
+}
+
+impl FromLegacy for GlobalRegistry {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [100, 213, 140, 104, 66, 152, 15, 238];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        GlobalRegistryV0::deserialize(&mut &data[..]).ok().map(Into::into)
+    }
+}
+
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct ProjectV0 {
+    pub project_id: String,
+    pub owner: Pubkey,
+    pub ipfs_cid: String,
+    pub carbon_tons_estimated: u64,
+    pub verification_status: VerificationStatus,
+    pub credits_issued: u64,
+    pub tokens_minted: u64,
+    pub bump: u8,
+    pub ecosystem_type: EcosystemType,
+    pub location: GeoLocationV0,
+    pub area_hectares: f64,
+    pub species_composition: Vec<String>,
+    pub biodiversity_index: f64,
+    pub establishment_date: i64,
+    pub above_ground_biomass: f64,
+    pub below_ground_biomass: f64,
+    pub soil_carbon_0_30cm: f64,
+    pub soil_carbon_30_100cm: f64,
+    pub sequestration_rate_annual: f64,
+    pub measurement_methodology: String,
+    pub uncertainty_percentage: f64,
+    pub vcs_methodology: String,
+    pub additionality_proof_cid: String,
+    pub permanence_guarantee_years: u16,
+    pub leakage_assessment: f64,
+    pub monitoring_plan_cid: String,
+    pub baseline_ecosystem_health: f64,
+    pub current_ecosystem_health: f64,
+    pub species_count_baseline: u32,
+    pub species_count_current: u32,
+    pub co_benefits: Vec<CoBenefit>,
+    pub vintage_year: u16,
+    pub quality_rating: u8,
+    pub price_per_ton: u64,
+    pub available_quantity: u64,
+}
+
+// Projects that were already issuing get a default-length crediting period
+// counted from establishment; the rest start theirs when verified.
+impl TryFrom<ProjectV0> for Project {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: ProjectV0) -> std::result::Result<Self, Self::Error> {
+        let (crediting_period_start, crediting_period_end) = if legacy.verification_status.is_issuing() {
+            let years = ProtocolParams::default().crediting_period_years as i64;
+            let start = legacy.establishment_date;
+            (start, start.saturating_add(years * Project::SECONDS_PER_YEAR))
+        } else {
+            (0, 0)
+        };
+
+        Ok(Self {
+            version: Self::VERSION,
+            project_id: legacy.project_id,
+            owner: legacy.owner,
+            creator: legacy.owner,
+            pending_owner: None,
+            ipfs_cid: legacy.ipfs_cid,
+            carbon_tons_estimated: legacy.carbon_tons_estimated,
+            verification_status: legacy.verification_status,
+            credits_issued: legacy.credits_issued,
+            tokens_minted: legacy.tokens_minted,
+            bump: legacy.bump,
+            ecosystem_type: legacy.ecosystem_type,
+            location: legacy.location.try_into()?,
+            area_hectares: fixed(legacy.area_hectares, Hectares::from_f64)?,
+            species_composition: legacy.species_composition,
+            biodiversity_index: fixed(legacy.biodiversity_index, Measure::from_f64)?,
+            establishment_date: legacy.establishment_date,
+            above_ground_biomass: fixed(legacy.above_ground_biomass, Tonnes::from_f64)?,
+            below_ground_biomass: fixed(legacy.below_ground_biomass, Tonnes::from_f64)?,
+            soil_carbon_0_30cm: fixed(legacy.soil_carbon_0_30cm, Tonnes::from_f64)?,
+            soil_carbon_30_100cm: fixed(legacy.soil_carbon_30_100cm, Tonnes::from_f64)?,
+            sequestration_rate_annual: fixed(legacy.sequestration_rate_annual, Tonnes::from_f64)?,
+            measurement_methodology: legacy.measurement_methodology,
+            uncertainty_percentage: fixed(legacy.uncertainty_percentage, BasisPoints::from_f64)?,
+            vcs_methodology: legacy.vcs_methodology,
+            additionality_proof_cid: legacy.additionality_proof_cid,
+            permanence_guarantee_years: legacy.permanence_guarantee_years,
+            leakage_assessment: fixed(legacy.leakage_assessment, BasisPoints::from_f64)?,
+            monitoring_plan_cid: legacy.monitoring_plan_cid,
+            baseline_ecosystem_health: fixed(legacy.baseline_ecosystem_health, Measure::from_f64)?,
+            current_ecosystem_health: fixed(legacy.current_ecosystem_health, Measure::from_f64)?,
+            species_count_baseline: legacy.species_count_baseline,
+            species_count_current: legacy.species_count_current,
+            co_benefits: legacy.co_benefits,
+            vintage_year: legacy.vintage_year,
+            quality_rating: legacy.quality_rating,
+            price_per_ton: legacy.price_per_ton,
+            available_quantity: legacy.available_quantity,
+            amendment_count: 0,
+            crediting_period_start,
+            crediting_period_end,
+            crediting_period_renewals: 0,
+            proponents: Vec::new(),
+            proponent_threshold: 0,
+            proponent_nonce: 0,
+            proponent_proposal_count: 0,
+            programme: None,
+            methodology: None,
+            baseline: None,
+            credited_stock: Tonnes::ZERO,
+            net_removals: Tonnes::ZERO,
+            monitoring_period_count: 0,
+            last_monitoring_period_end: 0,
+            buffer_rate: None,
+            buffer_balance: 0,
+            reversal_count: 0,
+            tokens_reversed: 0,
+            reversal_liability: 0,
+        })
+    }
+}
+
+impl FromLegacy for Project {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [205, 168, 189, 202, 181, 247, 142, 19];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        ProjectV0::deserialize(&mut &data[..]).ok()?.try_into().ok()
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct VerificationNodeV0 {
+    pub verifier_pubkey: Pubkey,
+    pub verifier_type: VerifierType,
+    pub credentials: Vec<String>,
+    pub reputation_score: u64,
+    pub verification_count: u64,
+    pub is_active: bool,
+    pub registration_date: i64,
+    pub specializations: Vec<EcosystemType>,
+}
+
+impl From<VerificationNodeV0> for VerificationNode {
+    fn from(legacy: VerificationNodeV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            verifier_pubkey: legacy.verifier_pubkey,
+            verifier_type: legacy.verifier_type,
+            credentials: legacy.credentials,
+            reputation_score: legacy.reputation_score,
+            verification_count: legacy.verification_count,
+            is_active: legacy.is_active,
+            registration_date: legacy.registration_date,
+            specializations: legacy.specializations,
+        }
+    }
+}
+
+impl FromLegacy for VerificationNode {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [146, 19, 185, 141, 119, 125, 56, 85];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        VerificationNodeV0::deserialize(&mut &data[..]).ok().map(Into::into)
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct MonitoringDataV0 {
+    pub project_id: String,
+    pub timestamp: i64,
+    pub satellite_imagery_cid: String,
+    pub ndvi_index: f64,
+    pub water_quality: WaterQualityV0,
+    pub temperature_data: Vec<f64>,
+    pub tide_data: Vec<TideReadingV0>,
+    pub iot_sensor_data: Vec<SensorReadingV0>,
+    pub ecosystem_health_score: f64,
+}
+
+impl TryFrom<MonitoringDataV0> for MonitoringData {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: MonitoringDataV0) -> std::result::Result<Self, Self::Error> {
+        let temperature_data = legacy
+            .temperature_data
+            .into_iter()
+            .map(|value| fixed(value, Measure::from_f64))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Self {
+            version: Self::VERSION,
+            project_id: legacy.project_id,
+            timestamp: legacy.timestamp,
+            satellite_imagery_cid: legacy.satellite_imagery_cid,
+            ndvi_index: fixed(legacy.ndvi_index, Measure::from_f64)?,
+            water_quality: legacy.water_quality.try_into()?,
+            temperature_data,
+            tide_data: convert_all(legacy.tide_data)?,
+            iot_sensor_data: convert_all(legacy.iot_sensor_data)?,
+            ecosystem_health_score: fixed(legacy.ecosystem_health_score, Measure::from_f64)?,
+        })
+    }
+}
+
+impl FromLegacy for MonitoringData {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [227, 98, 53, 103, 195, 21, 12, 228];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        MonitoringDataV0::deserialize(&mut &data[..]).ok()?.try_into().ok()
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct CarbonCreditListingV0 {
+    pub project_id: String,
+    pub seller: Pubkey,
+    pub vintage_year: u16,
+    pub quantity_available: u64,
+    pub price_per_ton: u64,
+    pub quality_rating: u8,
+    pub co_benefits: Vec<CoBenefit>,
+    pub certification_standards: Vec<String>,
+    pub listing_date: i64,
+    pub expiry_date: i64,
+    pub is_active: bool,
+}
+
+impl From<CarbonCreditListingV0> for CarbonCreditListing {
+    fn from(legacy: CarbonCreditListingV0) -> Self {
+        Self {
+            version: Self::VERSION,
+            project_id: legacy.project_id,
+            seller: legacy.seller,
+            vintage_year: legacy.vintage_year,
+            quantity_available: legacy.quantity_available,
+            price_per_ton: legacy.price_per_ton,
+            quality_rating: legacy.quality_rating,
+            co_benefits: legacy.co_benefits,
+            certification_standards: legacy.certification_standards,
+            listing_date: legacy.listing_date,
+            expiry_date: legacy.expiry_date,
+            is_active: legacy.is_active,
+        }
+    }
+}
+
+impl FromLegacy for CarbonCreditListing {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [205, 167, 142, 84, 219, 152, 204, 53];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        CarbonCreditListingV0::deserialize(&mut &data[..]).ok().map(Into::into)
+    }
+}
+
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+pub struct ImpactReportV0 {
+    pub project_id: String,
+    pub reporting_period_start: i64,
+    pub reporting_period_end: i64,
+    pub carbon_sequestered: f64,
+    pub ecosystem_health_improvement: f64,
+    pub biodiversity_increase: f64,
+    pub community_benefits: Vec<CommunityBenefitV0>,
+    pub economic_impact: EconomicImpactV0,
+    pub sdg_contributions: Vec<u8>,
+    pub verification_report_cid: String,
+}
+
+impl TryFrom<ImpactReportV0> for ImpactReport {
+    type Error = InvalidLegacyValue;
+
+    fn try_from(legacy: ImpactReportV0) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            version: Self::VERSION,
+            project_id: legacy.project_id,
+            reporting_period_start: legacy.reporting_period_start,
+            reporting_period_end: legacy.reporting_period_end,
+            carbon_sequestered: fixed(legacy.carbon_sequestered, Tonnes::from_f64)?,
+            ecosystem_health_improvement: fixed(legacy.ecosystem_health_improvement, Measure::from_f64)?,
+            biodiversity_increase: fixed(legacy.biodiversity_increase, BasisPoints::from_f64)?,
+            community_benefits: convert_all(legacy.community_benefits)?,
+            economic_impact: legacy.economic_impact.try_into()?,
+            sdg_contributions: legacy.sdg_contributions,
+            verification_report_cid: legacy.verification_report_cid,
+        })
+    }
+}
+
+impl FromLegacy for ImpactReport {
+    const LEGACY_DISCRIMINATOR: [u8; 8] = [34, 96, 240, 56, 227, 89, 198, 11];
+
+    fn from_legacy(data: &[u8]) -> Option<Self> {
+        ImpactReportV0::deserialize(&mut &data[..]).ok()?.try_into().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+    use anchor_lang::Discriminator;
+
+    fn discriminator(preimage: &str) -> Vec<u8> {
+        hash(preimage.as_bytes()).to_bytes()[..8].to_vec()
+    }
+
+    fn check<T: Discriminator + FromLegacy>(name: &str) {
+        assert_eq!(
+            T::LEGACY_DISCRIMINATOR.to_vec(),
+            discriminator(&format!("account:{name}"))
+        );
+        assert_eq!(
+            T::DISCRIMINATOR.to_vec(),
+            discriminator(&format!("account:{name}:versioned"))
+        );
+    }
+
+    #[test]
+    fn discriminators_match_their_preimages() {
+        check::<GlobalRegistry>("GlobalRegistry");
+        check::<Project>("Project");
+        check::<VerificationNode>("VerificationNode");
+        check::<MonitoringData>("MonitoringData");
+        check::<CarbonCreditListing>("CarbonCreditListing");
+        check::<ImpactReport>("ImpactReport");
+    }
+}
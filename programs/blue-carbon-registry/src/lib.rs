@@ -1,16 +1,18 @@
 #![allow(deprecated)]
 
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
-    associated_token::AssociatedToken,
-};
 
 pub mod models;
+pub mod legacy;
+pub mod geo;
+pub mod fixed;
+pub mod carbon;
 pub mod auth_utils;
 pub mod instructions;
 
 use crate::models::*;
+use crate::carbon::CarbonEstimate;
+use crate::fixed::{BasisPoints, Hectares, Tonnes};
 use crate::instructions::*;
 
 declare_id!("6q7u2DH9vswSbpPYZLyaamAyBXQeXBCPfcgmi1dikuQB");
@@ -45,15 +47,16 @@ pub mod blue_carbon_registry {
         instructions::verify_project(ctx, verified_carbon_tons)
     }
 
-    /// Mints carbon credits for verified projects only
+    /// Mints carbon credits of a vintage inside a verified project's crediting period
     pub fn mint_verified_credits(
         ctx: Context<MintVerifiedCredits>, 
-        amount: u64
+        amount: u64,
+        vintage_year: u16,
     ) -> Result<()> {
-        instructions::mint_verified_credits(ctx, amount)
+        instructions::mint_verified_credits(ctx, amount, vintage_year)
     }
 
-    /// Legacy mint_credits (deprecated - use mint_verified_credits)
+    /// Legacy mint_credits (disabled - use mint_verified_credits)
     pub fn mint_credits(ctx: Context<MintCredits>, amount: u64) -> Result<()> {
         instructions::mint_credits(ctx, amount)
     }
@@ -78,14 +81,153 @@ pub mod blue_carbon_registry {
         instructions::track_impact(ctx, data)
     }
 
-    /// Register a comprehensive blue carbon project with ecosystem data
-    pub fn register_blue_carbon_project(
-        ctx: Context<RegisterBlueProject>,
+    /// Register a comprehensive blue carbon project with ecosystem data.
+    /// Remaining accounts: the GeoCell PDAs covering the boundary, then the
+    /// projects already listed in those cells. The boundary may touch at
+    /// most `max_geo_cells_per_project` cells, each listing fewer than
+    /// `max_projects_per_geo_cell` projects (see GeoCell).
+    pub fn register_blue_carbon_project<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterBlueProject<'info>>,
         project_data: BlueProjectData,
     ) -> Result<()> {
         instructions::register_blue_carbon_project(ctx, project_data)
     }
 
+    /// Register a grouped project design that sites are added under
+    pub fn register_programme(
+        ctx: Context<RegisterProgramme>,
+        programme_data: ProgrammeData,
+    ) -> Result<()> {
+        instructions::register_programme(ctx, programme_data)
+    }
+
+    /// Verifier validates a programme design
+    pub fn validate_programme(ctx: Context<ValidateProgramme>) -> Result<()> {
+        instructions::validate_programme(ctx)
+    }
+
+    /// Onboard a site under a validated programme as a new project.
+    /// Remaining accounts as for register_blue_carbon_project.
+    pub fn add_programme_site<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddProgrammeSite<'info>>,
+        site: ProgrammeSiteData,
+    ) -> Result<()> {
+        instructions::add_programme_site(ctx, site)
+    }
+
+    /// Publish a crediting methodology projects can register under
+    pub fn register_methodology(
+        ctx: Context<RegisterMethodology>,
+        methodology_data: MethodologyData,
+    ) -> Result<()> {
+        instructions::register_methodology(ctx, methodology_data)
+    }
+
+    /// Close a methodology to new projects
+    pub fn deprecate_methodology(ctx: Context<DeprecateMethodology>) -> Result<()> {
+        instructions::deprecate_methodology(ctx)
+    }
+
+    /// Edit a registered project; every edit is recorded as an amendment
+    pub fn update_project<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateProject<'info>>,
+        update: ProjectUpdate,
+    ) -> Result<()> {
+        instructions::update_project(ctx, update)
+    }
+
+    /// Nominate a new owner for a project
+    pub fn propose_project_transfer(ctx: Context<ManageProjectTransfer>, new_owner: Pubkey) -> Result<()> {
+        instructions::propose_project_transfer(ctx, new_owner)
+    }
+
+    /// Accept ownership of a project (signed by the nominated owner)
+    pub fn accept_project_transfer(ctx: Context<ManageProjectTransfer>) -> Result<()> {
+        instructions::accept_project_transfer(ctx)
+    }
+
+    /// Withdraw a pending project ownership transfer
+    pub fn cancel_project_transfer(ctx: Context<ManageProjectTransfer>) -> Result<()> {
+        instructions::cancel_project_transfer(ctx)
+    }
+
+    /// Withdraw a project that never issued credits and reclaim its rent
+    pub fn deregister_project(ctx: Context<DeregisterProject>) -> Result<()> {
+        instructions::deregister_project(ctx)
+    }
+
+    /// Owner submits a pending project for review
+    pub fn submit_for_review(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::submit_for_review(ctx)
+    }
+
+    /// Verifier rejects a project under review
+    pub fn reject_project(ctx: Context<TransitionProject>, reason_cid: String) -> Result<()> {
+        instructions::reject_project(ctx, reason_cid)
+    }
+
+    /// Owner resubmits a rejected project for review
+    pub fn resubmit_project(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::resubmit_project(ctx)
+    }
+
+    /// Verifier moves a verified project into monitoring
+    pub fn enter_monitoring(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::enter_monitoring(ctx)
+    }
+
+    /// Auditor suspends credit issuance for a project
+    pub fn suspend_project(ctx: Context<TransitionProject>, reason_cid: String) -> Result<()> {
+        instructions::suspend_project(ctx, reason_cid)
+    }
+
+    /// Admin lifts a project suspension
+    pub fn reinstate_project(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::reinstate_project(ctx)
+    }
+
+    /// Auditor marks a project as expired
+    pub fn expire_project(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::expire_project(ctx)
+    }
+
+    /// Permissionless crank expiring a project past its crediting period
+    pub fn expire_crediting_period(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::expire_crediting_period(ctx)
+    }
+
+    /// Owner applies to renew an ended crediting period
+    pub fn renew_crediting_period(ctx: Context<TransitionProject>) -> Result<()> {
+        instructions::renew_crediting_period(ctx)
+    }
+
+    /// Set co-proponents, their shares and the approval threshold
+    pub fn set_project_proponents(
+        ctx: Context<SetProjectProponents>,
+        proponents: Vec<Proponent>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_project_proponents(ctx, proponents, threshold)
+    }
+
+    /// Propose a project action for co-proponent approval
+    pub fn create_proponent_proposal(
+        ctx: Context<CreateProponentProposal>,
+        action: ProponentAction,
+    ) -> Result<()> {
+        instructions::create_proponent_proposal(ctx, action)
+    }
+
+    /// Approve a pending co-proponent proposal
+    pub fn approve_proponent_proposal(ctx: Context<ApproveProponentProposal>) -> Result<()> {
+        instructions::approve_proponent_proposal(ctx)
+    }
+
+    /// Cancel an unexecuted co-proponent proposal
+    pub fn cancel_proponent_proposal(ctx: Context<CancelProponentProposal>) -> Result<()> {
+        instructions::cancel_proponent_proposal(ctx)
+    }
+
     /// Register a verification entity
     pub fn register_verifier(
         ctx: Context<RegisterVerifier>,
@@ -94,14 +236,88 @@ pub mod blue_carbon_registry {
         instructions::register_verifier(ctx, verifier_data)
     }
 
+    /// Replace a verifier's credentials and specializations
+    pub fn update_verifier_profile(
+        ctx: Context<UpdateVerifierProfile>,
+        credentials: Vec<String>,
+        specializations: Vec<EcosystemType>,
+    ) -> Result<()> {
+        instructions::update_verifier_profile(ctx, credentials, specializations)
+    }
+
+    /// Verifier sets a project's non-permanence risk buffer rate
+    pub fn set_buffer_rate(ctx: Context<SetBufferRate>, rate: Option<BasisPoints>) -> Result<()> {
+        instructions::set_buffer_rate(ctx, rate)
+    }
+
+    /// Auditor reports lost carbon; suspends the project and cancels
+    /// buffer credits, charging the owner for what the buffer can't cover
+    pub fn report_reversal(
+        ctx: Context<ReportReversal>,
+        evidence_cid: String,
+        affected_area: Hectares,
+        co2e_lost: Tonnes,
+        intentional: bool,
+    ) -> Result<()> {
+        instructions::report_reversal(ctx, evidence_cid, affected_area, co2e_lost, intentional)
+    }
+
+    /// Owner returns credits to the buffer pool against reversal liability
+    pub fn compensate_reversal(ctx: Context<CompensateReversal>, amount: u64) -> Result<()> {
+        instructions::compensate_reversal(ctx, amount)
+    }
+
+    /// Propose the carbon baseline; locked when the project is verified
+    pub fn set_carbon_baseline(
+        ctx: Context<SetCarbonBaseline>,
+        carbon_stock: Tonnes,
+        emissions_annual: Tonnes,
+        evidence_cid: String,
+    ) -> Result<()> {
+        instructions::set_carbon_baseline(ctx, carbon_stock, emissions_annual, evidence_cid)
+    }
+
+    /// Verifier credits a monitoring period's net removals from the measured
+    /// stock against the baseline
+    pub fn record_monitoring_period(
+        ctx: Context<RecordMonitoringPeriod>,
+        period_end: i64,
+        carbon_stock: Tonnes,
+    ) -> Result<()> {
+        instructions::record_monitoring_period(ctx, period_end, carbon_stock)
+    }
+
+    /// Creditable tCO2e derived from a project's measurements; the ceiling
+    /// verification applies. Simulate to read it off-chain.
+    pub fn estimate_creditable_carbon(ctx: Context<EstimateCreditableCarbon>) -> Result<CarbonEstimate> {
+        instructions::estimate_creditable_carbon(ctx)
+    }
+
     /// Multi-party project verification with enhanced validation
     pub fn multi_party_verify_project(
         ctx: Context<MultiPartyVerifyProject>,
         verified_carbon_tons: u64,
         quality_rating: u8,
         verification_report_cid: String,
+        verification_report_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::multi_party_verify_project(ctx, verified_carbon_tons, quality_rating, verification_report_cid)
+        instructions::multi_party_verify_project(
+            ctx,
+            verified_carbon_tons,
+            quality_rating,
+            verification_report_cid,
+            verification_report_hash,
+        )
+    }
+
+    /// Attach a content-addressed document to a project
+    pub fn add_project_document(
+        ctx: Context<AddProjectDocument>,
+        kind: DocumentKind,
+        cid: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::add_project_document(ctx, kind, cid, content_hash)
     }
 
     /// Submit environmental monitoring data
@@ -114,6 +330,14 @@ pub mod blue_carbon_registry {
         instructions::submit_monitoring_data(ctx, project_id, timestamp, monitoring_data)
     }
 
+    /// Append late IoT sensor readings to submitted monitoring data
+    pub fn append_sensor_readings(
+        ctx: Context<AppendSensorReadings>,
+        readings: Vec<SensorReading>,
+    ) -> Result<()> {
+        instructions::append_sensor_readings(ctx, readings)
+    }
+
     /// Create marketplace listing for carbon credits
     pub fn create_marketplace_listing(
         ctx: Context<CreateMarketplaceListing>,
@@ -132,5 +356,144 @@ pub mod blue_carbon_registry {
     ) -> Result<()> {
         instructions::generate_impact_report(ctx, project_id, reporting_period_end, report_data)
     }
-}
 
+    /// Grant a role to a wallet (admin only)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        holder: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        instructions::grant_role(ctx, holder, role)
+    }
+
+    /// Revoke a previously granted role and close its account
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        instructions::revoke_role(ctx)
+    }
+
+    /// Nominate a new registry admin; takes effect once they accept
+    pub fn propose_admin_transfer(ctx: Context<ManageAdminTransfer>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin_transfer(ctx, new_admin)
+    }
+
+    /// Accept a pending admin transfer (signed by the nominated admin)
+    pub fn accept_admin_transfer(ctx: Context<ManageAdminTransfer>) -> Result<()> {
+        instructions::accept_admin_transfer(ctx)
+    }
+
+    /// Withdraw a pending admin transfer
+    pub fn cancel_admin_transfer(ctx: Context<ManageAdminTransfer>) -> Result<()> {
+        instructions::cancel_admin_transfer(ctx)
+    }
+
+    /// Move mint authority to a new generation PDA without changing registry seeds
+    pub fn rotate_mint_authority(ctx: Context<RotateMintAuthority>, generation: u16) -> Result<()> {
+        instructions::rotate_mint_authority(ctx, generation)
+    }
+
+    /// Turn on M-of-N multisig approval for privileged admin actions
+    pub fn enable_admin_multisig(
+        ctx: Context<EnableAdminMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::enable_admin_multisig(ctx, members, threshold)
+    }
+
+    /// Change the multisig key set or threshold (requires an approved proposal)
+    pub fn update_admin_multisig(
+        ctx: Context<UpdateAdminMultisig>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::update_admin_multisig(ctx, members, threshold)
+    }
+
+    /// Return to single admin key mode (requires an approved proposal)
+    pub fn disable_admin_multisig(ctx: Context<DisableAdminMultisig>) -> Result<()> {
+        instructions::disable_admin_multisig(ctx)
+    }
+
+    /// Propose a privileged admin action for multisig approval
+    pub fn create_admin_proposal(ctx: Context<CreateAdminProposal>, action: AdminAction) -> Result<()> {
+        instructions::create_admin_proposal(ctx, action)
+    }
+
+    /// Approve a pending admin proposal as a multisig member
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+        instructions::approve_admin_proposal(ctx)
+    }
+
+    /// Cancel an unexecuted admin proposal
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal(ctx)
+    }
+
+    /// Pause or resume a registry subsystem
+    pub fn set_pause(ctx: Context<SetPause>, subsystem: Subsystem, paused: bool) -> Result<()> {
+        instructions::set_pause(ctx, subsystem, paused)
+    }
+
+    /// Create the registry config with default protocol parameters and the buffer pool
+    pub fn initialize_registry_config(ctx: Context<InitializeRegistryConfig>) -> Result<()> {
+        instructions::initialize_registry_config(ctx)
+    }
+
+    /// Update tunable protocol parameters
+    pub fn update_registry_config(
+        ctx: Context<UpdateRegistryConfig>,
+        params: ProtocolParams,
+    ) -> Result<()> {
+        instructions::update_registry_config(ctx, params)
+    }
+
+    /// Close a monitoring record after archiving its data off-chain
+    pub fn archive_monitoring_data(ctx: Context<ArchiveMonitoringData>, archive_cid: String) -> Result<()> {
+        instructions::archive_monitoring_data(ctx, archive_cid)
+    }
+
+    /// Close an impact report after archiving its data off-chain
+    pub fn archive_impact_report(ctx: Context<ArchiveImpactReport>, archive_cid: String) -> Result<()> {
+        instructions::archive_impact_report(ctx, archive_cid)
+    }
+
+    /// Close a sold-out, inactive or expired marketplace listing
+    pub fn close_listing(ctx: Context<CloseListing>) -> Result<()> {
+        instructions::close_listing(ctx)
+    }
+
+    /// Reserve the project ID of a project registered before IDs were indexed
+    pub fn index_existing_project(ctx: Context<IndexExistingProject>) -> Result<()> {
+        instructions::index_existing_project(ctx)
+    }
+
+    /// Upgrade the registry account to the current layout in place
+    pub fn migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+        instructions::migrate_registry(ctx)
+    }
+
+    /// Upgrade a project account to the current layout in place
+    pub fn migrate_project(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_project(ctx)
+    }
+
+    /// Upgrade a verifier account to the current layout in place
+    pub fn migrate_verifier(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_verifier(ctx)
+    }
+
+    /// Upgrade a monitoring data account to the current layout in place
+    pub fn migrate_monitoring_data(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_monitoring_data(ctx)
+    }
+
+    /// Upgrade a marketplace listing to the current layout in place
+    pub fn migrate_listing(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_listing(ctx)
+    }
+
+    /// Upgrade an impact report to the current layout in place
+    pub fn migrate_impact_report(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_impact_report(ctx)
+    }
+}
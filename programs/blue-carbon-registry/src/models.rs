@@ -1,28 +1,141 @@
 use anchor_lang::prelude::*;
+use crate::fixed::*;
 
 // Blue Carbon Ecosystem Types
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum EcosystemType {
     Mangrove,
     Seagrass,
     SaltMarsh,
+    #[default]
     MixedBlueCarbon,
 }
 
 // Verification Status with enhanced options
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum VerificationStatus {
+    #[default]
     Pending,
     UnderReview,
     Verified,
     Rejected,
     Monitoring,
     Expired,
+    Suspended,
 }
 
-// Verifier types for multi-party verification
+impl VerificationStatus {
+    // Whether credits may be minted and listed for a project in this state
+    pub fn is_issuing(&self) -> bool {
+        matches!(self, Self::Verified | Self::Monitoring)
+    }
+}
+
+// One party jointly running a project, with its share of issued credits
+// and revenue
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Proponent {
+    pub key: Pubkey,
+    pub role: ProponentRole,
+    pub share_bps: u16,
+}
+
+// What the site would hold and emit without the project. Credits are
+// issued on removals beyond it. Proposed by the owner and locked when the
+// project is first verified.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CarbonBaseline {
+    pub carbon_stock: Tonnes,     // tCO2e across the project area
+    pub emissions_annual: Tonnes, // tCO2e/yr
+    pub evidence_cid: String,
+    // Zero until validation
+    pub locked_at: i64,
+}
+
+// A proponent's part of an amount split by share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ShareAllocation {
+    pub proponent: Pubkey,
+    pub amount: u64,
+}
+
+// Project lifecycle moves. `next` is the single transition table; every
+// instruction that changes `verification_status` goes through it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProjectTransition {
+    SubmitForReview,
+    Verify,
+    Reject,
+    Resubmit,
+    EnterMonitoring,
+    // Carbon science amended after verification, see update_project
+    Reopen,
+    Suspend,
+    Reinstate,
+    Expire,
+    // Permissionless crank once the crediting period has ended
+    EndCreditingPeriod,
+    // Owner asks for a new crediting period after the last one ended
+    Renew,
+}
+
+// Who may perform a ProjectTransition
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransitionAuthority {
+    Owner,
+    Role(Role),
+    Anyone,
+}
+
+impl ProjectTransition {
+    pub fn next(self, from: &VerificationStatus) -> Option<VerificationStatus> {
+        use VerificationStatus::*;
+        let to = match (self, from) {
+            (Self::SubmitForReview, Pending) => UnderReview,
+            (Self::Verify, UnderReview) => Verified,
+            (Self::Reject, UnderReview) => Rejected,
+            (Self::Resubmit, Rejected) => UnderReview,
+            (Self::EnterMonitoring, Verified) => Monitoring,
+            (Self::Reopen, Verified | Monitoring) => UnderReview,
+            (Self::Suspend, Verified | Monitoring) => Suspended,
+            // A reinstated project resumes as Verified and re-enters
+            // monitoring explicitly
+            (Self::Reinstate, Suspended) => Verified,
+            (Self::Expire, Verified | Monitoring | Suspended) => Expired,
+            (Self::EndCreditingPeriod, Verified | Monitoring | Suspended) => Expired,
+            (Self::Renew, Expired) => UnderReview,
+            _ => return None,
+        };
+        Some(to)
+    }
+
+    pub fn authority(self) -> TransitionAuthority {
+        match self {
+            Self::SubmitForReview | Self::Resubmit | Self::Reopen | Self::Renew => {
+                TransitionAuthority::Owner
+            }
+            Self::Verify | Self::Reject | Self::EnterMonitoring => {
+                TransitionAuthority::Role(Role::Verifier)
+            }
+            Self::Suspend | Self::Expire => TransitionAuthority::Role(Role::Auditor),
+            Self::Reinstate => TransitionAuthority::Role(Role::Admin),
+            Self::EndCreditingPeriod => TransitionAuthority::Anyone,
+        }
+    }
+
+    // Suspending and expiring stay available while verification is paused
+    pub fn subsystem(self) -> Option<Subsystem> {
+        match self {
+            Self::Suspend | Self::Expire | Self::EndCreditingPeriod => None,
+            _ => Some(Subsystem::Verification),
+        }
+    }
+}
+
+// Verifier types for multi-party verification
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
 pub enum VerifierType {
+    #[default]
     ScientificInstitution,
     GovernmentAgency,
     CertificationBody,
@@ -31,6 +144,54 @@ pub enum VerifierType {
     ThirdPartyValidator,
 }
 
+// Access control roles, stored per holder in RoleAssignment PDAs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Admin,
+    Verifier,
+    DataProvider,
+    MarketplaceOperator,
+    Auditor,
+}
+
+// Kinds of evidence a project can attach as a ProjectDocument
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DocumentKind {
+    ProjectDesign,
+    Additionality,
+    MonitoringPlan,
+    VerificationReport,
+    FpicConsent,
+    Permit,
+}
+
+// Part a co-proponent plays in running a project
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProponentRole {
+    Developer,
+    Community,
+    Government,
+    Landholder,
+    Investor,
+    TechnicalPartner,
+}
+
+// Registry subsystems that can be paused independently
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Subsystem {
+    Registration,
+    Verification,
+    Minting,
+    Transfers,
+    Marketplace,
+}
+
+impl Subsystem {
+    pub fn flag(self) -> u8 {
+        1 << self as u8
+    }
+}
+
 // Co-benefits tracking
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum CoBenefit {
@@ -46,35 +207,50 @@ pub enum CoBenefit {
 // Geographic location data
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
 pub struct GeoLocation {
-    pub latitude: f64,
-    pub longitude: f64,
-    pub polygon_coordinates: Vec<[f64; 2]>,
+    pub latitude: Degrees,
+    pub longitude: Degrees,
+    // Closed boundary ring of [longitude, latitude] pairs
+    pub polygon_coordinates: Vec<[Degrees; 2]>,
     pub country_code: String,
     pub region_name: String,
 }
 
+impl GeoLocation {
+    // A closed ring repeats its first vertex, so a triangle needs 4
+    pub const MIN_POLYGON_VERTICES: usize = 4;
+    pub const MAX_POLYGON_VERTICES: usize = 32;
+
+    /// Area enclosed by `polygon_coordinates` on the WGS84 ellipsoid, see
+    /// geo::ring_area.
+    pub fn polygon_area(&self) -> Hectares {
+        // A raw Hectares unit is one square metre
+        Hectares(crate::geo::ring_area(&self.polygon_coordinates))
+    }
+}
+
 // Water quality measurements
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct WaterQuality {
-    pub ph_level: f64,
-    pub salinity: f64,
-    pub dissolved_oxygen: f64,
-    pub turbidity: f64,
+    pub ph_level: Measure,
+    pub salinity: Measure, // ppt
+    pub dissolved_oxygen: Measure, // mg/L
+    pub turbidity: Measure, // NTU
     pub nutrients: NutrientLevels,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct NutrientLevels {
-    pub nitrogen: f64,
-    pub phosphorus: f64,
-    pub potassium: f64,
+    // mg/L
+    pub nitrogen: Measure,
+    pub phosphorus: Measure,
+    pub potassium: Measure,
 }
 
 // Tide reading data
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct TideReading {
     pub timestamp: i64,
-    pub tide_height: f64,
+    pub tide_height: Measure, // metres
     pub tide_type: String,
 }
 
@@ -83,11 +259,11 @@ pub struct TideReading {
 pub struct SensorReading {
     pub sensor_id: String,
     pub timestamp: i64,
-    pub co2_flux: f64,
-    pub soil_moisture: f64,
-    pub ph_level: f64,
-    pub temperature: f64,
-    pub humidity: f64,
+    pub co2_flux: Measure, // µmol/m²/s, negative for uptake
+    pub soil_moisture: BasisPoints,
+    pub ph_level: Measure,
+    pub temperature: Measure, // °C
+    pub humidity: BasisPoints,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -95,23 +271,83 @@ pub struct CommunityBenefit {
     pub benefit_type: String,
     pub households_affected: u32,
     pub jobs_created: u32,
-    pub income_increase_percentage: f64,
+    pub income_increase_percentage: BasisPoints,
     pub capacity_building_programs: u32,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct EconomicImpact {
     pub direct_revenue: u64,
     pub indirect_benefits: u64,
     pub cost_savings: u64,
-    pub roi_percentage: f64,
-    pub payback_period_years: f64,
+    pub roi_percentage: BasisPoints,
+    pub payback_period_years: Measure,
+}
+
+// Gregorian year containing a unix timestamp (UTC)
+pub fn calendar_year(timestamp: i64) -> i64 {
+    // Days-to-civil conversion, counting eras of 400 years from 0000-03-01
+    let days = timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+    let year = year_of_era + era * 400;
+    if month_index >= 10 { year + 1 } else { year }
+}
+
+// Borsh-encoded length of `value`, without the account discriminator
+pub fn serialized_len<T: AnchorSerialize + ?Sized>(value: &T) -> usize {
+    let mut buffer = Vec::new();
+    // Writing into a Vec cannot fail
+    let _ = value.serialize(&mut buffer);
+    buffer.len()
+}
+
+// Accounts whose space follows their contents. They are created with room
+// for their instruction payload and trimmed by fit_account_space once
+// populated, then resized again whenever a collection grows or shrinks.
+pub trait DynamicSpace: AnchorSerialize {
+    // Bytes kept free beyond the current encoding, for fields that are
+    // expected to be filled in place later without a realloc
+    fn reserved_space(&self) -> usize {
+        0
+    }
+
+    fn space(&self) -> usize {
+        8 + serialized_len(self) + self.reserved_space()
+    }
+
+    // Space to create the account with: the empty account plus the
+    // `payload_len` bytes of instruction data its variable-length fields
+    // are copied from. Anchor deserializes a new account before the handler
+    // runs, so it cannot start smaller than its empty encoding.
+    fn init_space(payload_len: usize) -> usize
+    where
+        Self: Default,
+    {
+        Self::default().space() + payload_len
+    }
+}
+
+// Accounts carrying a layout version byte. Those that replaced a baseline
+// layout use sha256("account:<Name>:versioned")[..8] as their discriminator,
+// so migrate_* never mistakes one for the other. Bump VERSION whenever a
+// deployed layout changes and teach the matching migrate_* instruction the
+// old one.
+pub trait Versioned {
+    const VERSION: u8;
+
+    fn version(&self) -> u8;
 }
 
 // Global registry account for tracking overall system state
-#[account]
+#[account(discriminator = [3, 150, 59, 135, 112, 236, 64, 164])]
 #[derive(Debug, Default)]
 pub struct GlobalRegistry {
+    // Layout version, see migrate_registry
+    pub version: u8,
     pub total_credits_issued: u64,
     pub total_projects: u64,
     pub admin: Pubkey,
@@ -119,25 +355,246 @@ pub struct GlobalRegistry {
     pub carbon_token_mint: Pubkey,
     pub bump: u8,
     pub mint_authority_bump: u8,
+    // Admin nominated by propose_admin_transfer, waiting to accept
+    pub pending_admin: Option<Pubkey>,
+    // 0 while the registry PDA itself is the mint authority, otherwise the
+    // seed of the dedicated [b"mint_authority", generation] PDA
+    pub mint_authority_generation: u16,
+    // When set, privileged actions need an approved AdminProposal
+    pub multisig_enabled: bool,
+    // Bumped on every multisig reconfiguration to invalidate open proposals
+    pub multisig_nonce: u32,
+    pub admin_proposal_count: u64,
+    // Bitmask of paused subsystems, see Subsystem::flag
+    pub paused_subsystems: u8,
+}
+
+impl Versioned for GlobalRegistry {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
 
 impl GlobalRegistry {
     pub const LEN: usize = 
+        1 + // version
         8 + // total_credits_issued
         8 + // total_projects  
         32 + // admin
         32 + // mint_authority
         32 + // carbon_token_mint
         1 + // bump
-        1; // mint_authority_bump
+        1 + // mint_authority_bump
+        1 + 32 + // pending_admin
+        2 + // mint_authority_generation
+        1 + // multisig_enabled
+        4 + // multisig_nonce
+        8 + // admin_proposal_count
+        1; // paused_subsystems
+
+    pub fn is_paused(&self, subsystem: Subsystem) -> bool {
+        self.paused_subsystems & subsystem.flag() != 0
+    }
 }
 
-// Enhanced Blue Carbon Project with comprehensive ecosystem data
+// Role granted to a wallet by the registry admin.
+// Seeds: [b"role", holder, role as u8]
 #[account]
+#[derive(Debug)]
+pub struct RoleAssignment {
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    pub const LEN: usize =
+        32 + // holder
+        1 + // role
+        32 + // granted_by
+        8 + // granted_at
+        1; // bump
+}
+
+// Privileged actions that need co-approval while multisig mode is on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    VerifyProject { project: Pubkey, verified_carbon_tons: u64 },
+    GrantRole { holder: Pubkey, role: Role },
+    RevokeRole { holder: Pubkey, role: Role },
+    ProposeAdminTransfer { new_admin: Pubkey },
+    CancelAdminTransfer,
+    Unpause { subsystem: Subsystem },
+    UpdateConfig { params: ProtocolParams },
+    UpdateMultisig { members: Vec<Pubkey>, threshold: u8 },
+    DisableMultisig,
+    // Binds the approval to the exact MethodologyData through its hash
+    RegisterMethodology { methodology: Pubkey, data_hash: [u8; 32] },
+    DeprecateMethodology { methodology: Pubkey },
+    // Binds the approval to the exact VerifierData through its hash
+    AccreditVerifier { verifier_authority: Pubkey, data_hash: [u8; 32] },
+    MultiPartyVerifyProject {
+        project: Pubkey,
+        verifier_authority: Pubkey,
+        verified_carbon_tons: u64,
+        quality_rating: u8,
+        verification_report_hash: [u8; 32],
+    },
+    RotateMintAuthority { generation: u16 },
+    InitializeRegistryConfig,
+    IndexExistingProject { project: Pubkey },
+    // Covers migrate_registry as well as the per-account migrations
+    MigrateAccount { target: Pubkey },
+}
+
+impl AdminAction {
+    // Largest variant is UpdateMultisig
+    pub const LEN: usize = 1 + 4 + 32 * AdminMultisig::MAX_MEMBERS + 1;
+}
+
+// M-of-N admin key set. Seeds: [b"admin_multisig"]
+#[account]
+#[derive(Debug)]
+pub struct AdminMultisig {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    pub const MAX_MEMBERS: usize = 10;
+
+    pub const LEN: usize =
+        4 + 32 * Self::MAX_MEMBERS + // members
+        1 + // threshold
+        1; // bump
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+// Proposal collecting multisig approvals for one AdminAction.
+// Seeds: [b"admin_proposal", index]
+#[account]
+#[derive(Debug)]
+pub struct AdminProposal {
+    pub index: u64,
+    pub multisig_nonce: u32,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub approvals: Vec<Pubkey>,
+    pub threshold: u8,
+    pub created_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const LEN: usize =
+        8 + // index
+        4 + // multisig_nonce
+        32 + // proposer
+        AdminAction::LEN + // action
+        4 + 32 * AdminMultisig::MAX_MEMBERS + // approvals
+        1 + // threshold
+        8 + // created_at
+        1 + // executed
+        1; // bump
+}
+
+// Tunable protocol parameters, updatable by the admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProtocolParams {
+    // Token base units minted per verified tCO2e (10^decimals of the mint);
+    // fixed once the registry has issued credits
+    pub tokens_per_ton: u64,
+    pub starting_verifier_reputation: u64,
+    pub verification_reputation_reward: u64,
+    pub min_quality_rating: u8,
+    pub max_quality_rating: u8,
+    // Charged to the project owner at registration, paid into the registry PDA
+    pub registration_fee_lamports: u64,
+    // Length of each crediting period granted at verification
+    pub crediting_period_years: u16,
+    // How many times a crediting period can be renewed after the first
+    pub max_crediting_renewals: u8,
+    // Share of each issuance withheld for the buffer pool when neither the
+    // project nor its methodology sets one
+    pub buffer_bps: u16,
+    // String limits
+    pub max_project_id_len: u16,
+    pub max_cid_len: u16,
+    pub max_methodology_len: u16,
+    pub max_name_len: u16,
+    // Spatial index limits, see GeoCell
+    pub max_geo_cells_per_project: u8,
+    pub max_projects_per_geo_cell: u8,
+}
+
+impl ProtocolParams {
+    pub const LEN: usize =
+        8 + 8 + 8 + // tokens_per_ton, reputation
+        1 + 1 + // quality rating range
+        8 + // registration_fee_lamports
+        2 + 1 + // crediting periods
+        2 + // buffer_bps
+        2 * 4 + // string limits
+        1 + 1; // spatial index limits
+}
+
+impl Default for ProtocolParams {
+    fn default() -> Self {
+        Self {
+            tokens_per_ton: 10u64.pow(6),
+            starting_verifier_reputation: 100,
+            verification_reputation_reward: 10,
+            min_quality_rating: 1,
+            max_quality_rating: 5,
+            registration_fee_lamports: 0,
+            crediting_period_years: 10,
+            max_crediting_renewals: 2,
+            buffer_bps: 1_000,
+            max_project_id_len: Project::ID_LEN as u16,
+            max_cid_len: Project::CID_LEN as u16,
+            max_methodology_len: Project::METHODOLOGY_LEN as u16,
+            max_name_len: Project::NAME_LEN as u16,
+            max_geo_cells_per_project: 16,
+            max_projects_per_geo_cell: 8,
+        }
+    }
+}
+
+// Seeds: [b"registry_config"]
+#[account]
+#[derive(Debug)]
+pub struct RegistryConfig {
+    pub params: ProtocolParams,
+    pub bump: u8,
+}
+
+impl RegistryConfig {
+    pub const LEN: usize = ProtocolParams::LEN + 1;
+}
+
+// Enhanced Blue Carbon Project with comprehensive ecosystem data
+#[account(discriminator = [164, 63, 99, 123, 220, 106, 100, 0])]
 #[derive(Debug, Default)]
 pub struct Project {
+    // Layout version, see migrate_project
+    pub version: u8,
     pub project_id: String,
+    // Current owner; may change through accept_project_transfer
     pub owner: Pubkey,
+    // Original registrant, fixed forever and used in the PDA seeds:
+    // [b"project", creator, project_id]
+    pub creator: Pubkey,
+    // Owner nominated by propose_project_transfer, waiting to accept
+    pub pending_owner: Option<Pubkey>,
     pub ipfs_cid: String,
     pub carbon_tons_estimated: u64,
     pub verification_status: VerificationStatus,
@@ -148,30 +605,30 @@ pub struct Project {
     // Blue Carbon Ecosystem Data
     pub ecosystem_type: EcosystemType,
     pub location: GeoLocation,
-    pub area_hectares: f64,
+    pub area_hectares: Hectares,
     pub species_composition: Vec<String>,
-    pub biodiversity_index: f64,
+    pub biodiversity_index: Measure,
     pub establishment_date: i64,
     
-    // Carbon Science Data
-    pub above_ground_biomass: f64,
-    pub below_ground_biomass: f64,
-    pub soil_carbon_0_30cm: f64,
-    pub soil_carbon_30_100cm: f64,
-    pub sequestration_rate_annual: f64,
+    // Carbon Science Data: stocks in tC/ha, sequestration in tCO2e/yr
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon_0_30cm: Tonnes,
+    pub soil_carbon_30_100cm: Tonnes,
+    pub sequestration_rate_annual: Tonnes,
     pub measurement_methodology: String,
-    pub uncertainty_percentage: f64,
+    pub uncertainty_percentage: BasisPoints,
     
     // Verification & Compliance
     pub vcs_methodology: String,
     pub additionality_proof_cid: String,
     pub permanence_guarantee_years: u16,
-    pub leakage_assessment: f64,
+    pub leakage_assessment: BasisPoints,
     pub monitoring_plan_cid: String,
     
     // Impact Metrics
-    pub baseline_ecosystem_health: f64,
-    pub current_ecosystem_health: f64,
+    pub baseline_ecosystem_health: Measure,
+    pub current_ecosystem_health: Measure,
     pub species_count_baseline: u32,
     pub species_count_current: u32,
     pub co_benefits: Vec<CoBenefit>,
@@ -181,41 +638,498 @@ pub struct Project {
     pub quality_rating: u8,
     pub price_per_ton: u64,
     pub available_quantity: u64,
+
+    // Number of ProjectAmendment records written by update_project
+    pub amendment_count: u32,
+
+    // Window in which credited vintages must fall, set at verification.
+    // Both zero until the project is first verified; the end is exclusive.
+    pub crediting_period_start: i64,
+    pub crediting_period_end: i64,
+    pub crediting_period_renewals: u8,
+
+    // Co-proponents and their shares. Empty means the owner acts alone;
+    // otherwise minting, listing and changing this list need an approved
+    // ProponentProposal reaching `proponent_threshold`.
+    pub proponents: Vec<Proponent>,
+    pub proponent_threshold: u8,
+    // Bumped whenever the proponent list changes to invalidate open proposals
+    pub proponent_nonce: u32,
+    pub proponent_proposal_count: u64,
+
+    // Programme this project was onboarded under as a site, if any. Sites
+    // inherit the programme's design fields and cannot change them.
+    pub programme: Option<Pubkey>,
+
+    // Methodology account the project was registered under, if any.
+    // Verification checks the project against it.
+    pub methodology: Option<Pubkey>,
+
+    // Once the baseline is locked, minting is capped by `net_removals`
+    // (tCO2e after deductions) recorded by monitoring periods.
+    // `credited_stock` is the highest gross stock (tCO2e) credited so far,
+    // starting at the baseline stock, so losses must be regrown before
+    // stock gains are credited again.
+    pub baseline: Option<CarbonBaseline>,
+    pub credited_stock: Tonnes,
+    pub net_removals: Tonnes,
+    pub monitoring_period_count: u32,
+    pub last_monitoring_period_end: i64,
+
+    // Non-permanence risk rating from the verifier: the share of each
+    // issuance withheld for the buffer pool. None falls back to the
+    // methodology's buffer percentage, then the registry default.
+    pub buffer_rate: Option<BasisPoints>,
+    // Tokens held in the buffer pool on this project's behalf
+    pub buffer_balance: u64,
+
+    // Number of Reversal records written by report_reversal
+    pub reversal_count: u32,
+    // Tokens written off by those reversals, capped at credits_issued
+    pub tokens_reversed: u64,
+    // Tokens the owner still owes the buffer pool for reversals it could
+    // not cover; nothing more is minted until this is paid off with
+    // compensate_reversal
+    pub reversal_liability: u64,
 }
 
 impl Project {
-    const ID_LEN: usize = 32;
-    const CID_LEN: usize = 46;
-    const METHODOLOGY_LEN: usize = 100;
-    const COUNTRY_CODE_LEN: usize = 4;
-    const REGION_NAME_LEN: usize = 50;
-    
+    pub const ID_LEN: usize = 32;
+    // Fits CIDv0 (46) and base32 CIDv1 with a 32-byte digest (up to 63)
+    pub const CID_LEN: usize = 64;
+    pub const METHODOLOGY_LEN: usize = 100;
+    pub const COUNTRY_CODE_LEN: usize = 4;
+    pub const REGION_NAME_LEN: usize = 50;
+    pub const NAME_LEN: usize = 32;
+    pub const MAX_SPECIES: usize = 10;
+    pub const MAX_CO_BENEFITS: usize = 10;
+
+    pub const MAX_PROPONENTS: usize = 10;
+
+    pub fn is_proponent(&self, key: &Pubkey) -> bool {
+        self.proponents.iter().any(|proponent| proponent.key == *key)
+    }
+
+    /// Splits `amount` by proponent share. Rounding remainders go to the
+    /// first proponent so the parts always add up to `amount`.
+    pub fn proponent_split(&self, amount: u64) -> Vec<ShareAllocation> {
+        let mut parts: Vec<ShareAllocation> = self
+            .proponents
+            .iter()
+            .map(|proponent| ShareAllocation {
+                proponent: proponent.key,
+                amount: (amount as u128 * proponent.share_bps as u128
+                    / BasisPoints::HUNDRED_PERCENT.0 as u128) as u64,
+            })
+            .collect();
+        let assigned: u64 = parts.iter().map(|part| part.amount).sum();
+        if let Some(first) = parts.first_mut() {
+            first.amount += amount - assigned;
+        }
+        parts
+    }
+
+    /// Share of an issuance withheld for the buffer pool. `methodology`
+    /// must be the project's own.
+    pub fn effective_buffer_rate(&self, methodology: Option<&Methodology>, default_bps: u16) -> BasisPoints {
+        self.buffer_rate
+            .or(methodology.map(|methodology| methodology.buffer_percentage))
+            .unwrap_or(BasisPoints(default_bps as i32))
+    }
+
+    pub fn baseline_locked(&self) -> bool {
+        self.baseline.as_ref().is_some_and(|baseline| baseline.locked_at != 0)
+    }
+
+    // Julian year, so periods do not drift with leap years
+    pub const SECONDS_PER_YEAR: i64 = 31_557_600;
+
+    pub fn has_crediting_period(&self) -> bool {
+        self.crediting_period_end != 0
+    }
+
+    pub fn crediting_period_ended(&self, now: i64) -> bool {
+        self.has_crediting_period() && now >= self.crediting_period_end
+    }
+
+    /// Whether `vintage_year` falls in a calendar year overlapping the
+    /// crediting period.
+    pub fn vintage_in_crediting_period(&self, vintage_year: u16) -> bool {
+        self.has_crediting_period()
+            && (calendar_year(self.crediting_period_start)..=calendar_year(self.crediting_period_end - 1))
+                .contains(&(vintage_year as i64))
+    }
+}
+
+impl DynamicSpace for Project {
+    // Room for a nominee, so propose_project_transfer never reallocs
+    fn reserved_space(&self) -> usize {
+        if self.pending_owner.is_none() { 32 } else { 0 }
+    }
+}
+
+impl Versioned for Project {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+// Reserves a project_id across all owners so dependent PDAs seeded by
+// project_id resolve to exactly one project.
+// Seeds: [b"project_id", project_id]
+#[account]
+#[derive(Debug)]
+pub struct ProjectIdIndex {
+    pub project_id: String,
+    pub project: Pubkey,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl ProjectIdIndex {
     pub const LEN: usize =
-        4 + Self::ID_LEN + // project_id
-        32 + // owner
-        4 + Self::CID_LEN + // ipfs_cid
-        8 + // carbon_tons_estimated
-        1 + // verification_status
-        8 + // credits_issued
-        8 + // tokens_minted
-        1 + // bump
-        1 + // ecosystem_type
-        8 + 8 + 4 + 100 + 4 + Self::COUNTRY_CODE_LEN + 4 + Self::REGION_NAME_LEN + // location
-        8 + // area_hectares
-        4 + 320 + // species_composition (up to 10 species, 32 chars each)
-        8 + // biodiversity_index
-        8 + // establishment_date
-        8 + 8 + 8 + 8 + 8 + // carbon measurements
-        4 + Self::METHODOLOGY_LEN + // methodology
-        8 + // uncertainty
-        4 + Self::METHODOLOGY_LEN + // vcs_methodology
-        4 + Self::CID_LEN + // additionality_proof_cid
-        2 + // permanence_guarantee_years
-        8 + // leakage_assessment
-        4 + Self::CID_LEN + // monitoring_plan_cid
-        8 + 8 + 4 + 4 + // impact metrics
-        4 + 280 + // co_benefits (up to 10 benefits)
-        2 + 1 + 8 + 8; // quality & trading data
+        4 + Project::ID_LEN + // project_id
+        32 + // project
+        8 + // registered_at
+        1; // bump
+}
+
+// Spatial index bucket: the projects whose boundary bounding box touches
+// this geohash cell. Registration checks the boundaries of every project
+// listed here for overlap before claiming the cell.
+//
+// This constrains registration: a boundary whose bounding box touches more
+// than max_geo_cells_per_project cells (about 4.9 km square each) fails
+// with TooManyGeoCells, and a cell already listing
+// max_projects_per_geo_cell projects turns away the next with GeoCellFull,
+// whether or not they overlap. Every covering cell and listed project is
+// passed to the registering transaction, which is what bounds the ceilings
+// below; the admin can raise either parameter up to them.
+// Seeds: [b"geo_cell", geohash]
+#[account]
+#[derive(Debug)]
+pub struct GeoCell {
+    pub geohash: String,
+    pub projects: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl GeoCell {
+    // Ceilings of max_projects_per_geo_cell and max_geo_cells_per_project
+    pub const MAX_PROJECTS: usize = 32;
+    pub const MAX_CELLS_PER_PROJECT: usize = 32;
+}
+
+impl DynamicSpace for GeoCell {}
+
+// Conditions every site under a programme must meet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct EligibilityCriteria {
+    // ISO 3166-1 alpha-2 codes sites may be located in
+    pub country_codes: Vec<String>,
+    // Closed ring sites must lie inside; empty for no boundary
+    pub boundary: Vec<[Degrees; 2]>,
+    pub min_site_area: Hectares,
+    pub max_site_area: Hectares,
+    pub max_uncertainty: BasisPoints,
+    pub max_leakage: BasisPoints,
+}
+
+impl EligibilityCriteria {
+    pub const MAX_COUNTRIES: usize = 10;
+}
+
+// Grouped project: one validated design that site instances are added
+// under, each becoming its own Project.
+// Seeds: [b"programme", creator, programme_id]
+#[account]
+#[derive(Debug, Default)]
+pub struct Programme {
+    pub version: u8,
+    pub programme_id: String,
+    pub owner: Pubkey,
+    pub creator: Pubkey,
+    pub design_cid: String,
+    pub ecosystem_type: EcosystemType,
+    pub vcs_methodology: String,
+    pub measurement_methodology: String,
+    pub additionality_proof_cid: String,
+    pub monitoring_plan_cid: String,
+    pub permanence_guarantee_years: u16,
+    pub eligibility: EligibilityCriteria,
+    // Verifier who validated the design; sites can only be added after
+    pub validated_by: Option<Pubkey>,
+    pub validated_at: i64,
+    pub site_count: u32,
+    pub total_area_hectares: Hectares,
+    pub bump: u8,
+}
+
+impl DynamicSpace for Programme {
+    // Room for the validator, so validate_programme never reallocs
+    fn reserved_space(&self) -> usize {
+        if self.validated_by.is_none() { 32 } else { 0 }
+    }
+}
+
+impl Versioned for Programme {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+// Net removals credited for one monitoring period, measured against the
+// project's locked baseline. All amounts in tCO2e.
+// Seeds: [b"monitoring_period", project, index]
+#[account]
+#[derive(Debug)]
+pub struct MonitoringPeriod {
+    pub project: Pubkey,
+    pub index: u32,
+    pub period_start: i64,
+    pub period_end: i64,
+    // Measured gross stock at the end of the period
+    pub carbon_stock: Tonnes,
+    // Growth beyond the highest stock credited before
+    pub stock_gain: Tonnes,
+    // Baseline emissions avoided over the period
+    pub avoided_emissions: Tonnes,
+    pub leakage_deduction: Tonnes,
+    pub uncertainty_deduction: Tonnes,
+    pub net_removals: Tonnes,
+    pub recorded_by: Pubkey,
+    pub bump: u8,
+}
+
+impl MonitoringPeriod {
+    pub const LEN: usize =
+        32 + // project
+        4 + // index
+        8 + 8 + // period_start, period_end
+        8 * 6 + // carbon_stock .. net_removals
+        32 + // recorded_by
+        1; // bump
+}
+
+// Stored carbon lost from a project, reported from monitoring evidence.
+// The buffer pool covers an unintentional loss as far as it can; the rest,
+// or the whole of an intentional loss, becomes the owner's liability.
+// Seeds: [b"reversal", project, index]
+#[account]
+#[derive(Debug)]
+pub struct Reversal {
+    pub project: Pubkey,
+    pub index: u32,
+    pub evidence_cid: String,
+    pub affected_area: Hectares,
+    pub co2e_lost: Tonnes,
+    pub intentional: bool,
+    // co2e_lost in tokens
+    pub tokens_lost: u64,
+    // Burned from the buffer pool
+    pub buffer_cancelled: u64,
+    // Added to the project's reversal_liability
+    pub liability: u64,
+    pub reported_by: Pubkey,
+    pub reported_at: i64,
+    pub bump: u8,
+}
+
+impl Reversal {
+    pub const LEN: usize =
+        32 + // project
+        4 + // index
+        4 + Project::CID_LEN + // evidence_cid
+        8 + 8 + // affected_area, co2e_lost
+        1 + // intentional
+        8 * 3 + // tokens_lost, buffer_cancelled, liability
+        32 + // reported_by
+        8 + // reported_at
+        1; // bump
+}
+
+// Default carbon stocks and removal rate a methodology assumes for one
+// ecosystem: stocks in tC/ha, sequestration in tCO2e/ha/yr
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EmissionFactor {
+    pub ecosystem_type: EcosystemType,
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon: Tonnes,
+    pub sequestration_rate_annual: Tonnes,
+}
+
+// Crediting methodology curated by the registry admin. Projects reference
+// one at registration and are checked against it at verification.
+// Published methodologies are never edited; a revision is registered as a
+// new account and the old one deprecated.
+// Seeds: [b"methodology", methodology_id, methodology_version]
+#[account]
+#[derive(Debug, Default)]
+pub struct Methodology {
+    pub version: u8,
+    // Standard identifier, e.g. "VM0033"; projects quote it in vcs_methodology
+    pub methodology_id: String,
+    // Revision of the methodology, e.g. "2.1"
+    pub methodology_version: String,
+    pub document_cid: String,
+    pub eligible_ecosystems: Vec<EcosystemType>,
+    pub emission_factors: Vec<EmissionFactor>,
+    // Longest allowed gap between monitoring submissions
+    pub monitoring_interval_days: u16,
+    // Share of each issuance withheld for the non-permanence buffer
+    pub buffer_percentage: BasisPoints,
+    // Projects with higher uncertainty are not eligible
+    pub max_uncertainty: BasisPoints,
+    // Uncertainty up to this level is not deducted from credited tons
+    pub uncertainty_allowance: BasisPoints,
+    pub max_leakage: BasisPoints,
+    // Deprecated methodologies cannot be chosen by new projects
+    pub active: bool,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+impl Methodology {
+    // Both are PDA seeds, so at most 32 bytes
+    pub const ID_LEN: u16 = 32;
+    pub const VERSION_LEN: u16 = 16;
+    // One per EcosystemType
+    pub const MAX_ECOSYSTEMS: usize = 4;
+
+    pub fn is_eligible(&self, ecosystem_type: &EcosystemType) -> bool {
+        self.eligible_ecosystems.contains(ecosystem_type)
+    }
+
+    pub fn emission_factor(&self, ecosystem_type: &EcosystemType) -> Option<&EmissionFactor> {
+        self.emission_factors
+            .iter()
+            .find(|factor| factor.ecosystem_type == *ecosystem_type)
+    }
+}
+
+impl DynamicSpace for Methodology {}
+
+impl Versioned for Methodology {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+// One update_project call, kept for audit.
+// Seeds: [b"amendment", project, index]
+#[account]
+pub struct ProjectAmendment {
+    pub project: Pubkey,
+    pub index: u32,
+    pub editor: Pubkey,
+    pub timestamp: i64,
+    pub previous_status: VerificationStatus,
+    pub new_status: VerificationStatus,
+    pub carbon_science_changed: bool,
+    pub update: ProjectUpdate,
+    pub bump: u8,
+}
+
+impl ProjectAmendment {
+    pub const BASE_LEN: usize =
+        32 + // project
+        4 + // index
+        32 + // editor
+        8 + // timestamp
+        1 + 1 + // previous_status, new_status
+        1 + // carbon_science_changed
+        1; // bump
+
+    pub fn space_for(update: &ProjectUpdate) -> usize {
+        Self::BASE_LEN + serialized_len(update)
+    }
+}
+
+// One version of a project document. A newer upload of the same kind links
+// back through `supersedes`, and the older one is marked `superseded_by`.
+// Seeds: [b"document", project, content_hash]
+#[account]
+#[derive(Debug)]
+pub struct ProjectDocument {
+    pub project: Pubkey,
+    pub kind: DocumentKind,
+    pub cid: String,
+    pub content_hash: [u8; 32], // sha256 of the document content
+    pub uploader: Pubkey,
+    pub uploaded_at: i64,
+    pub supersedes: Option<Pubkey>,
+    pub superseded_by: Option<Pubkey>,
+    pub bump: u8,
+}
+
+impl ProjectDocument {
+    pub const LEN: usize =
+        32 + // project
+        1 + // kind
+        4 + Project::CID_LEN + // cid
+        32 + // content_hash
+        32 + // uploader
+        8 + // uploaded_at
+        1 + 32 + // supersedes
+        1 + 32 + // superseded_by
+        1; // bump
+}
+
+// Project actions that need co-proponent approval once a project has
+// proponents. Each carries the exact parameters being approved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProponentAction {
+    MintCredits { amount: u64, vintage_year: u16, recipient: Pubkey },
+    CreateListing {
+        seller: Pubkey,
+        vintage_year: u16,
+        quantity_available: u64,
+        price_per_ton: u64,
+    },
+    UpdateProponents { proponents: Vec<Proponent>, threshold: u8 },
+}
+
+impl ProponentAction {
+    // Largest variant is UpdateProponents
+    pub const LEN: usize = 1 + 4 + (32 + 1 + 2) * Project::MAX_PROPONENTS + 1;
+}
+
+// Proposal collecting co-proponent approvals for one ProponentAction.
+// Seeds: [b"proponent_proposal", project, index]
+#[account]
+#[derive(Debug)]
+pub struct ProponentProposal {
+    pub project: Pubkey,
+    pub index: u64,
+    pub proponent_nonce: u32,
+    pub proposer: Pubkey,
+    pub action: ProponentAction,
+    pub approvals: Vec<Pubkey>,
+    pub threshold: u8,
+    pub created_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl ProponentProposal {
+    pub const LEN: usize =
+        32 + // project
+        8 + // index
+        4 + // proponent_nonce
+        32 + // proposer
+        ProponentAction::LEN + // action
+        4 + 32 * Project::MAX_PROPONENTS + // approvals
+        1 + // threshold
+        8 + // created_at
+        1 + // executed
+        1; // bump
 }
 
 // Carbon measurement data structure
@@ -223,13 +1137,13 @@ impl Project {
 pub struct CarbonMeasurement {
     pub project_id: String,
     pub measurement_date: i64,
-    pub above_ground_biomass: f64,
-    pub below_ground_biomass: f64,
-    pub soil_carbon_0_30cm: f64,
-    pub soil_carbon_30_100cm: f64,
-    pub sequestration_rate_annual: f64,
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon_0_30cm: Tonnes,
+    pub soil_carbon_30_100cm: Tonnes,
+    pub sequestration_rate_annual: Tonnes,
     pub methodology: String,
-    pub uncertainty_percentage: f64,
+    pub uncertainty_percentage: BasisPoints,
     pub measurer_authority: Pubkey,
     pub verification_status: VerificationStatus,
     pub satellite_imagery_cid: String,
@@ -237,8 +1151,10 @@ pub struct CarbonMeasurement {
 }
 
 // Multi-party verification node
-#[account]
+#[account(discriminator = [182, 249, 75, 248, 146, 245, 178, 114])]
+#[derive(Default)]
 pub struct VerificationNode {
+    pub version: u8,
     pub verifier_pubkey: Pubkey,
     pub verifier_type: VerifierType,
     pub credentials: Vec<String>,
@@ -249,23 +1165,59 @@ pub struct VerificationNode {
     pub specializations: Vec<EcosystemType>,
 }
 
+impl VerificationNode {
+    pub const MAX_CREDENTIALS: usize = 10;
+    pub const MAX_SPECIALIZATIONS: usize = 4;
+}
+
+impl DynamicSpace for VerificationNode {}
+
+impl Versioned for VerificationNode {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
 // Environmental monitoring data
-#[account]
+#[account(discriminator = [233, 245, 60, 183, 150, 4, 127, 159])]
+#[derive(Default)]
 pub struct MonitoringData {
+    pub version: u8,
     pub project_id: String,
     pub timestamp: i64,
     pub satellite_imagery_cid: String,
-    pub ndvi_index: f64,
+    pub ndvi_index: Measure,
     pub water_quality: WaterQuality,
-    pub temperature_data: Vec<f64>,
+    pub temperature_data: Vec<Measure>, // °C
     pub tide_data: Vec<TideReading>,
     pub iot_sensor_data: Vec<SensorReading>,
-    pub ecosystem_health_score: f64,
+    pub ecosystem_health_score: Measure,
+}
+
+impl MonitoringData {
+    // One reading every 15 minutes for a day
+    pub const MAX_TEMPERATURE_READINGS: usize = 96;
+    pub const MAX_TIDE_READINGS: usize = 48;
+    pub const MAX_SENSOR_READINGS: usize = 64;
+}
+
+impl DynamicSpace for MonitoringData {}
+
+impl Versioned for MonitoringData {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
 
 // Marketplace listing for carbon credits
-#[account]
+#[account(discriminator = [228, 43, 191, 216, 99, 79, 186, 140])]
+#[derive(Default)]
 pub struct CarbonCreditListing {
+    pub version: u8,
     pub project_id: String,
     pub seller: Pubkey,
     pub vintage_year: u16,
@@ -279,31 +1231,51 @@ pub struct CarbonCreditListing {
     pub is_active: bool,
 }
 
+impl CarbonCreditListing {
+    pub const MAX_CERTIFICATIONS: usize = 10;
+}
+
+impl DynamicSpace for CarbonCreditListing {}
+
+impl Versioned for CarbonCreditListing {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
 // Impact reporting structure
-#[account]
+#[account(discriminator = [64, 58, 208, 134, 42, 137, 96, 211])]
+#[derive(Default)]
 pub struct ImpactReport {
+    pub version: u8,
     pub project_id: String,
     pub reporting_period_start: i64,
     pub reporting_period_end: i64,
-    pub carbon_sequestered: f64,
-    pub ecosystem_health_improvement: f64,
-    pub biodiversity_increase: f64,
+    pub carbon_sequestered: Tonnes, // tCO2e
+    pub ecosystem_health_improvement: Measure,
+    // Change in species count since the baseline
+    pub biodiversity_increase: BasisPoints,
     pub community_benefits: Vec<CommunityBenefit>,
     pub economic_impact: EconomicImpact,
     pub sdg_contributions: Vec<u8>,
     pub verification_report_cid: String,
 }
 
-// Default implementations
-impl Default for VerificationStatus {
-    fn default() -> Self {
-        VerificationStatus::Pending
-    }
+impl ImpactReport {
+    pub const MAX_COMMUNITY_BENEFITS: usize = 10;
+    // One entry per Sustainable Development Goal
+    pub const MAX_SDG_CONTRIBUTIONS: usize = 17;
 }
 
-impl Default for EcosystemType {
-    fn default() -> Self {
-        EcosystemType::MixedBlueCarbon
+impl DynamicSpace for ImpactReport {}
+
+impl Versioned for ImpactReport {
+    const VERSION: u8 = 1;
+
+    fn version(&self) -> u8 {
+        self.version
     }
 }
 
@@ -315,28 +1287,143 @@ pub struct BlueProjectData {
     pub carbon_tons_estimated: u64,
     pub ecosystem_type: EcosystemType,
     pub location: GeoLocation,
-    pub area_hectares: f64,
+    pub area_hectares: Hectares,
     pub species_composition: Vec<String>,
-    pub biodiversity_index: f64,
-    pub above_ground_biomass: f64,
-    pub below_ground_biomass: f64,
-    pub soil_carbon_0_30cm: f64,
-    pub soil_carbon_30_100cm: f64,
-    pub sequestration_rate_annual: f64,
+    pub biodiversity_index: Measure,
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon_0_30cm: Tonnes,
+    pub soil_carbon_30_100cm: Tonnes,
+    pub sequestration_rate_annual: Tonnes,
     pub measurement_methodology: String,
-    pub uncertainty_percentage: f64,
+    pub uncertainty_percentage: BasisPoints,
     pub vcs_methodology: String,
     pub additionality_proof_cid: String,
     pub permanence_guarantee_years: u16,
-    pub leakage_assessment: f64,
+    pub leakage_assessment: BasisPoints,
     pub monitoring_plan_cid: String,
-    pub baseline_ecosystem_health: f64,
+    pub baseline_ecosystem_health: Measure,
     pub species_count_baseline: u32,
     pub co_benefits: Vec<CoBenefit>,
     pub vintage_year: u16,
     pub price_per_ton: u64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProgrammeData {
+    pub programme_id: String,
+    pub design_cid: String,
+    pub ecosystem_type: EcosystemType,
+    pub vcs_methodology: String,
+    pub measurement_methodology: String,
+    pub additionality_proof_cid: String,
+    pub monitoring_plan_cid: String,
+    pub permanence_guarantee_years: u16,
+    pub eligibility: EligibilityCriteria,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MethodologyData {
+    pub methodology_id: String,
+    pub methodology_version: String,
+    pub document_cid: String,
+    pub eligible_ecosystems: Vec<EcosystemType>,
+    pub emission_factors: Vec<EmissionFactor>,
+    pub monitoring_interval_days: u16,
+    pub buffer_percentage: BasisPoints,
+    pub max_uncertainty: BasisPoints,
+    pub uncertainty_allowance: BasisPoints,
+    pub max_leakage: BasisPoints,
+}
+
+// Site-specific data for add_programme_site; everything else comes from
+// the programme
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProgrammeSiteData {
+    pub project_id: String,
+    pub ipfs_cid: String,
+    pub carbon_tons_estimated: u64,
+    pub location: GeoLocation,
+    pub area_hectares: Hectares,
+    pub species_composition: Vec<String>,
+    pub biodiversity_index: Measure,
+    pub above_ground_biomass: Tonnes,
+    pub below_ground_biomass: Tonnes,
+    pub soil_carbon_0_30cm: Tonnes,
+    pub soil_carbon_30_100cm: Tonnes,
+    pub sequestration_rate_annual: Tonnes,
+    pub uncertainty_percentage: BasisPoints,
+    pub leakage_assessment: BasisPoints,
+    pub baseline_ecosystem_health: Measure,
+    pub species_count_baseline: u32,
+    pub co_benefits: Vec<CoBenefit>,
+    pub vintage_year: u16,
+    pub price_per_ton: u64,
+}
+
+impl ProgrammeSiteData {
+    /// Full project data for the site, with the design taken from
+    /// `programme`.
+    pub fn into_project_data(self, programme: &Programme) -> BlueProjectData {
+        BlueProjectData {
+            project_id: self.project_id,
+            ipfs_cid: self.ipfs_cid,
+            carbon_tons_estimated: self.carbon_tons_estimated,
+            ecosystem_type: programme.ecosystem_type.clone(),
+            location: self.location,
+            area_hectares: self.area_hectares,
+            species_composition: self.species_composition,
+            biodiversity_index: self.biodiversity_index,
+            above_ground_biomass: self.above_ground_biomass,
+            below_ground_biomass: self.below_ground_biomass,
+            soil_carbon_0_30cm: self.soil_carbon_0_30cm,
+            soil_carbon_30_100cm: self.soil_carbon_30_100cm,
+            sequestration_rate_annual: self.sequestration_rate_annual,
+            measurement_methodology: programme.measurement_methodology.clone(),
+            uncertainty_percentage: self.uncertainty_percentage,
+            vcs_methodology: programme.vcs_methodology.clone(),
+            additionality_proof_cid: programme.additionality_proof_cid.clone(),
+            permanence_guarantee_years: programme.permanence_guarantee_years,
+            leakage_assessment: self.leakage_assessment,
+            monitoring_plan_cid: programme.monitoring_plan_cid.clone(),
+            baseline_ecosystem_health: self.baseline_ecosystem_health,
+            species_count_baseline: self.species_count_baseline,
+            co_benefits: self.co_benefits,
+            vintage_year: self.vintage_year,
+            price_per_ton: self.price_per_ton,
+        }
+    }
+}
+
+// Partial project edit; `None` leaves a field unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProjectUpdate {
+    pub ipfs_cid: Option<String>,
+    pub species_composition: Option<Vec<String>>,
+    pub biodiversity_index: Option<Measure>,
+    pub additionality_proof_cid: Option<String>,
+    pub permanence_guarantee_years: Option<u16>,
+    pub monitoring_plan_cid: Option<String>,
+    pub co_benefits: Option<Vec<CoBenefit>>,
+    pub price_per_ton: Option<u64>,
+
+    // Carbon science fields; changing these after review starts sends a
+    // verified project back to UnderReview
+    pub carbon_tons_estimated: Option<u64>,
+    pub ecosystem_type: Option<EcosystemType>,
+    pub location: Option<GeoLocation>,
+    pub area_hectares: Option<Hectares>,
+    pub above_ground_biomass: Option<Tonnes>,
+    pub below_ground_biomass: Option<Tonnes>,
+    pub soil_carbon_0_30cm: Option<Tonnes>,
+    pub soil_carbon_30_100cm: Option<Tonnes>,
+    pub sequestration_rate_annual: Option<Tonnes>,
+    pub measurement_methodology: Option<String>,
+    pub uncertainty_percentage: Option<BasisPoints>,
+    pub vcs_methodology: Option<String>,
+    pub leakage_assessment: Option<BasisPoints>,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct VerifierData {
     pub verifier_type: VerifierType,
@@ -348,12 +1435,12 @@ pub struct VerifierData {
 pub struct MonitoringDataInput {
     pub project_id: String,
     pub satellite_imagery_cid: String,
-    pub ndvi_index: f64,
+    pub ndvi_index: Measure,
     pub water_quality: WaterQuality,
-    pub temperature_data: Vec<f64>,
+    pub temperature_data: Vec<Measure>,
     pub tide_data: Vec<TideReading>,
     pub iot_sensor_data: Vec<SensorReading>,
-    pub ecosystem_health_score: f64,
+    pub ecosystem_health_score: Measure,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -371,7 +1458,7 @@ pub struct ImpactReportData {
     pub project_id: String,
     pub reporting_period_start: i64,
     pub reporting_period_end: i64,
-    pub carbon_sequestered: f64,
+    pub carbon_sequestered: Tonnes,
     pub community_benefits: Vec<CommunityBenefit>,
     pub economic_impact: EconomicImpact,
     pub sdg_contributions: Vec<u8>,
@@ -0,0 +1,84 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  ensureRegistry,
+  expectError,
+} from "./helpers";
+
+describe("admin transfer", () => {
+  const newAdmin = Keypair.generate();
+
+  const propose = (authority: Keypair, nominee: PublicKey) =>
+    program.methods
+      .proposeAdminTransfer(nominee)
+      .accounts({ registry: registryPda, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  const accept = (authority: Keypair) =>
+    program.methods
+      .acceptAdminTransfer()
+      .accounts({ registry: registryPda, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+  });
+
+  // The other test files expect the provider wallet to be admin
+  after(async () => {
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    if (registry.admin.equals(newAdmin.publicKey)) {
+      await propose(newAdmin, admin.publicKey);
+      await accept(admin);
+    }
+  });
+
+  it("Hands the registry over once the nominee accepts", async () => {
+    await propose(admin, newAdmin.publicKey);
+
+    let registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.equal(registry.pendingAdmin.toString(), newAdmin.publicKey.toString());
+    assert.equal(registry.admin.toString(), admin.publicKey.toString());
+
+    // Nobody but the nominee can complete the handover
+    await expectError(accept(Keypair.generate()), "NotPendingAdmin");
+
+    await accept(newAdmin);
+    registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.equal(registry.admin.toString(), newAdmin.publicKey.toString());
+    assert.isNull(registry.pendingAdmin);
+
+    // The old key has lost its rights
+    await expectError(propose(admin, admin.publicKey), "Unauthorized");
+
+    await propose(newAdmin, admin.publicKey);
+    await accept(admin);
+    registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.equal(registry.admin.toString(), admin.publicKey.toString());
+
+    console.log("✅ Admin transferred and restored successfully");
+  });
+
+  it("Cannot accept a cancelled transfer", async () => {
+    await propose(admin, newAdmin.publicKey);
+    await program.methods
+      .cancelAdminTransfer()
+      .accounts({ registry: registryPda, authority: admin.publicKey } as any)
+      .signers([admin])
+      .rpc();
+
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.isNull(registry.pendingAdmin);
+    await expectError(accept(newAdmin), "NoPendingAdminTransfer");
+  });
+
+  it("Only the admin can nominate a successor", async () => {
+    const outsider = Keypair.generate();
+    await expectError(propose(outsider, outsider.publicKey), "Unauthorized");
+  });
+});
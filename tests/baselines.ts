@@ -0,0 +1,204 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  provider,
+  admin,
+  TOKEN_DECIMALS,
+  registryPda,
+  configPda,
+  projectPda,
+  projectIndexPda,
+  ipfsCid,
+  otherCid,
+  measure,
+  tonnes,
+  hectares,
+  basisPoints,
+  idlStruct,
+  squareSite,
+  randomLongitude,
+  spatialIndexAccounts,
+  SITE_AREA_HECTARES,
+  ensureRegistry,
+  fundedKeypair,
+  submitForReview,
+  verifyProject,
+  mintCredits,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+// 280 tC/ha over the site is about 127 224 tCO2e gross. Against a
+// 100 000 tCO2e baseline, after 5% leakage and 10% uncertainty, about
+// 23 276 tCO2e is creditable.
+const BASELINE_STOCK = 100_000;
+const CREDITABLE_TONS = 23_276;
+
+describe("carbon baselines", () => {
+  let owner: Keypair;
+  const longitude = randomLongitude(110);
+  let sites = 0;
+
+  const registerSite = async () => {
+    const data = idlStruct("BlueProjectData", {
+      project_id: uniqueId("BASE"),
+      ipfs_cid: ipfsCid,
+      carbon_tons_estimated: new anchor.BN(CREDITABLE_TONS),
+      ecosystem_type: { mangrove: {} },
+      location: squareSite(longitude + 0.1 * sites++, 0),
+      area_hectares: hectares(SITE_AREA_HECTARES),
+      species_composition: ["Rhizophora mucronata"],
+      biodiversity_index: measure(0.7),
+      above_ground_biomass: tonnes(100),
+      below_ground_biomass: tonnes(40),
+      soil_carbon_0_30cm: tonnes(60),
+      soil_carbon_30_100cm: tonnes(80),
+      sequestration_rate_annual: tonnes(5),
+      measurement_methodology: "IPCC 2013 Wetlands Supplement",
+      uncertainty_percentage: basisPoints(10),
+      vcs_methodology: "VM0033",
+      additionality_proof_cid: otherCid,
+      permanence_guarantee_years: 30,
+      leakage_assessment: basisPoints(5),
+      monitoring_plan_cid: otherCid,
+      baseline_ecosystem_health: measure(60),
+      species_count_baseline: 12,
+      co_benefits: [],
+      vintage_year: new Date().getUTCFullYear(),
+      price_per_ton: new anchor.BN(15),
+    });
+    const project = projectPda(owner.publicKey, data.projectId);
+    await program.methods
+      .registerBlueCarbonProject(data)
+      .accounts({
+        config: configPda,
+        project,
+        projectIndex: projectIndexPda(data.projectId),
+        methodology: null,
+        registry: registryPda,
+        projectOwner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(spatialIndexAccounts(data.location))
+      .signers([owner])
+      .rpc();
+    return project;
+  };
+
+  const setBaseline = (project: PublicKey, authority = owner) =>
+    program.methods
+      .setCarbonBaseline(tonnes(BASELINE_STOCK), tonnes(50), otherCid)
+      .accounts({
+        config: configPda,
+        project,
+        registry: registryPda,
+        owner: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([authority])
+      .rpc();
+
+  const recordPeriod = async (project: PublicKey, periodEnd: number, measuredStock: number, verifier = admin) => {
+    const { monitoringPeriodCount } = await program.account.project.fetch(project);
+    const seed = Buffer.alloc(4);
+    seed.writeUInt32LE(monitoringPeriodCount);
+    const monitoringPeriod = PublicKey.findProgramAddressSync(
+      [Buffer.from("monitoring_period"), project.toBuffer(), seed],
+      program.programId
+    )[0];
+    await program.methods
+      .recordMonitoringPeriod(new anchor.BN(periodEnd), tonnes(measuredStock))
+      .accounts({
+        project,
+        monitoringPeriod,
+        registry: registryPda,
+        verifierRole: null,
+        methodology: null,
+        verifier: verifier.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([verifier])
+      .rpc();
+    return monitoringPeriod;
+  };
+
+  const clusterTime = async () => provider.connection.getBlockTime(await provider.connection.getSlot());
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair(5);
+  });
+
+  it("Credits net removals above the locked baseline", async () => {
+    const project = await registerSite();
+    await expectError(setBaseline(project, await fundedKeypair()), "ConstraintHasOne");
+    await setBaseline(project);
+    await submitForReview(owner, project);
+    // The verifier assesses the baseline as submitted
+    await expectError(setBaseline(project), "ProjectNotEditable");
+
+    // Verified tons are capped at what the site holds above its baseline
+    await expectError(verifyProject(project, CREDITABLE_TONS + 1), "ExceedsCreditableCarbon");
+    await verifyProject(project, CREDITABLE_TONS);
+
+    const locked = await program.account.project.fetch(project);
+    assert.notEqual(locked.baseline.lockedAt.toNumber(), 0);
+    assert.equal(locked.creditedStock[0].toString(), tonnes(BASELINE_STOCK)[0].toString());
+    await expectError(setBaseline(project), "BaselineLocked");
+
+    // Nothing has been removed yet, so nothing can be minted
+    await expectError(mintCredits(owner, project, 1), "ExceedsNetRemovals");
+
+    // Each period credits the measured stock above the highest credited so
+    // far: 10 000 tCO2e of growth is 8 550 after 5% leakage and 10%
+    // uncertainty, plus a few seconds of avoided emissions
+    const closePeriod = async (measuredStock: number) => {
+      // Let the cluster clock move past the previous period end
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+      const periodEnd = await clusterTime();
+      const monitoringPeriod = await recordPeriod(project, periodEnd, measuredStock);
+      await expectError(recordPeriod(project, periodEnd, measuredStock), "InvalidMonitoringPeriod");
+      return program.account.monitoringPeriod.fetch(monitoringPeriod);
+    };
+    const netTons = async () =>
+      (await program.account.project.fetch(project)).netRemovals[0].div(new anchor.BN(1_000_000)).toNumber();
+
+    await expectError(
+      recordPeriod(project, await clusterTime(), BASELINE_STOCK + 10_000, await fundedKeypair()),
+      "MissingRole"
+    );
+    const first = await closePeriod(BASELINE_STOCK + 10_000);
+    assert.equal(first.index, 0);
+    assert.equal(first.carbonStock[0].toString(), tonnes(BASELINE_STOCK + 10_000)[0].toString());
+    assert.equal(first.stockGain[0].toString(), tonnes(10_000)[0].toString());
+    assert.equal(await netTons(), 8_550);
+
+    // Only net removals can be minted, well below the verified tons
+    await mintCredits(owner, project, 1_000);
+    await expectError(mintCredits(owner, project, 8_000), "ExceedsNetRemovals");
+
+    // A later period credits only its own measured growth
+    const second = await closePeriod(BASELINE_STOCK + 20_000);
+    assert.equal(second.index, 1);
+    assert.equal(second.stockGain[0].toString(), tonnes(10_000)[0].toString());
+    assert.equal(await netTons(), 17_100);
+
+    // A measured loss credits no growth
+    const third = await closePeriod(BASELINE_STOCK + 15_000);
+    assert.equal(third.stockGain[0].toString(), "0");
+
+    await mintCredits(owner, project, 8_000);
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.creditsIssued.toString(), (9_000 * 10 ** TOKEN_DECIMALS).toString());
+
+    console.log("✅ Net removals credited successfully");
+  });
+
+  it("Requires a baseline to verify a site", async () => {
+    const project = await registerSite();
+    await submitForReview(owner, project);
+    await expectError(verifyProject(project, 1_000), "BaselineRequired");
+  });
+});
@@ -112,6 +112,44 @@ describe("blue-carbon-registry", () => {
     }
   });
 
+  it("Initializes the registry config and buffer pool", async () => {
+    const [configPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("registry_config")],
+      program.programId
+    );
+    const [bufferPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("buffer_pool")],
+      program.programId
+    );
+
+    try {
+      await program.account.registryConfig.fetch(configPda);
+      console.log("Registry config already exists, skipping initialization");
+    } catch (error) {
+      await program.methods
+        .initializeRegistryConfig()
+        .accounts({
+          config: configPda,
+          registry: registryPda,
+          carbonTokenMint: tokenMint,
+          bufferPool: bufferPoolPda,
+          admin: projectOwner.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+    }
+
+    const config = await program.account.registryConfig.fetch(configPda);
+    assert.equal(config.params.tokensPerTon.toString(), "1000000");
+    assert.equal(config.params.maxQualityRating, 5);
+
+    // Minting needs no further setup step
+    const bufferPool = await getAccount(provider.connection, bufferPoolPda);
+    assert.equal(bufferPool.owner.toString(), registryPda.toString());
+  });
+
   it("Registers a project successfully", async () => {
     const carbonTonsEstimated = new anchor.BN(1000); // 1000 tons estimated
     const tx = await program.methods
@@ -141,9 +179,76 @@ describe("blue-carbon-registry", () => {
     console.log("✅ Project registered successfully");
   });
 
+  it("Submits monitoring data for the project", async () => {
+    // Fixed-point Measure, 10^6 raw units per unit
+    const measure = (value: number) => [new anchor.BN(Math.round(value * 1_000_000))] as any;
+    const timestamp = new anchor.BN(Math.floor(Date.now() / 1000));
+    const [monitoringPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("monitoring"), Buffer.from(projectId), timestamp.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [projectIndexPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("project_id"), Buffer.from(projectId)],
+      program.programId
+    );
+
+    await program.methods
+      .submitMonitoringData(projectId, timestamp, {
+        projectId,
+        satelliteImageryCid: ipfsCid,
+        ndviIndex: measure(0.62),
+        waterQuality: {
+          phLevel: measure(7.9),
+          salinity: measure(32),
+          dissolvedOxygen: measure(6.5),
+          turbidity: measure(3.2),
+          nutrients: {
+            nitrogen: measure(0.4),
+            phosphorus: measure(0.05),
+            potassium: measure(0.3),
+          },
+        },
+        temperatureData: [measure(28.5), measure(29.1)],
+        tideData: [],
+        iotSensorData: [],
+        ecosystemHealthScore: measure(82),
+      } as any)
+      .accounts({
+        monitoringData: monitoringPda,
+        project: projectPda,
+        projectIndex: projectIndexPda,
+        registry: registryPda,
+        dataProvider: projectOwner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const monitoring = await program.account.monitoringData.fetch(monitoringPda);
+    assert.equal(monitoring.projectId, projectId);
+    assert.equal(monitoring.temperatureData.length, 2);
+
+    // Created with room for the payload, then trimmed to its contents
+    const info = await provider.connection.getAccountInfo(monitoringPda);
+    const encoded = program.coder.accounts.encode("monitoringData", monitoring);
+    assert.equal(info!.data.length, (await encoded).length);
+
+    console.log("✅ Monitoring data submitted successfully");
+  });
+
   it("Verifies a project successfully", async () => {
     const verifiedCarbonTons = new anchor.BN(800); // Verify 800 tons
     
+    await program.methods
+      .submitForReview()
+      .accounts({
+        project: projectPda,
+        registry: registryPda,
+        authority: projectOwner.publicKey,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
     // Note: This assumes the project owner can verify the project
     // In a real scenario, this would be done by an admin
     const tx = await program.methods
@@ -185,11 +290,12 @@ describe("blue-carbon-registry", () => {
     const amountToMint = new anchor.BN(INITIAL_MINT_AMOUNT * (10 ** TOKEN_DECIMALS)); // 1000 tokens with 6 decimals
 
     const tx = await program.methods
-      .mintVerifiedCredits(amountToMint)
+      .mintVerifiedCredits(amountToMint, new Date().getUTCFullYear())
       .accounts({
         project: projectPda,
         registry: registryPda,
         carbonTokenMint: tokenMint,
+        mintAuthority: registryPda,
         recipientTokenAccount: projectTokenAccount,
         owner: projectOwner.publicKey,
         recipient: projectOwner.publicKey,
@@ -210,6 +316,8 @@ describe("blue-carbon-registry", () => {
     
     assert.equal(projectAccount.creditsIssued.toString(), amountToMint.toString());
     assert.equal(projectAccount.tokensMinted.toString(), amountToMint.toString());
+    // The default 10% buffer is withheld into the pool
+    assert.equal(projectAccount.bufferBalance.toString(), amountToMint.divn(10).toString());
     // Note: token account may have accumulated balance from previous test runs
     console.log("Token account balance:", tokenAccountInfo.amount.toString());
     console.log("Expected amount:", amountToMint.toString());
@@ -217,6 +325,26 @@ describe("blue-carbon-registry", () => {
     console.log("✅ Credits minted successfully");
   });
 
+  it("Rejects the legacy mint that skips the buffer", async () => {
+    try {
+      await program.methods
+        .mintCredits(new anchor.BN(10 ** TOKEN_DECIMALS))
+        .accounts({
+          mint: tokenMint,
+          project: projectPda,
+          owner: projectOwner.publicKey,
+          recipientTokenAccount: projectTokenAccount,
+          registry: registryPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+      assert.fail("Should have thrown error");
+    } catch (error: any) {
+      assert.ok(error.toString().includes("LegacyMintDisabled"));
+    }
+  });
+
   it("Transfers credits successfully", async () => {
     // Create token account for investor (owned by separate investor wallet)
     investorTokenAccount = (
@@ -307,4 +435,52 @@ describe("blue-carbon-registry", () => {
     
     console.log("✅ Credits retired successfully");
   });
+
+  it("Reports a reversal against the buffer pool", async () => {
+    const [bufferPoolPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("buffer_pool")],
+      program.programId
+    );
+    const reversalIndex = Buffer.alloc(4);
+    reversalIndex.writeUInt32LE(0);
+    const [reversalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reversal"), projectPda.toBuffer(), reversalIndex],
+      program.programId
+    );
+
+    const bufferBefore = await getAccount(provider.connection, bufferPoolPda);
+    // 10 tCO2e lost over 2 ha, covered by the buffer
+    const tonsLost = 10;
+
+    await program.methods
+      .reportReversal(
+        ipfsCid,
+        [new anchor.BN(2 * 10_000)] as any,
+        [new anchor.BN(tonsLost * 1_000_000)] as any,
+        false
+      )
+      .accounts({
+        project: projectPda,
+        reversal: reversalPda,
+        registry: registryPda,
+        carbonTokenMint: tokenMint,
+        bufferPool: bufferPoolPda,
+        auditor: projectOwner.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([projectOwner])
+      .rpc();
+
+    const projectAccount = await program.account.project.fetch(projectPda);
+    assert.deepEqual(projectAccount.verificationStatus, { suspended: {} });
+    assert.equal(projectAccount.reversalCount, 1);
+    assert.equal(projectAccount.reversalLiability.toString(), "0");
+
+    const cancelled = BigInt(tonsLost * (10 ** TOKEN_DECIMALS));
+    const bufferAfter = await getAccount(provider.connection, bufferPoolPda);
+    assert.equal(bufferAfter.amount.toString(), (bufferBefore.amount - cancelled).toString());
+
+    console.log("✅ Reversal reported successfully");
+  });
 });
@@ -0,0 +1,101 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  provider,
+  admin,
+  registryPda,
+  configPda,
+  projectIndexPda,
+  otherCid,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitForReview,
+  verifyProject,
+  mintCredits,
+  submitMonitoring,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("deregistration and archival", () => {
+  let owner: Keypair;
+
+  const deregister = (project: PublicKey, projectId: string, authority = owner) =>
+    program.methods
+      .deregisterProject()
+      .accounts({
+        project,
+        projectIndex: projectIndexPda(projectId),
+        registry: registryPda,
+        owner: authority.publicKey,
+      } as any)
+      .signers([authority])
+      .rpc();
+
+  const archive = (monitoringData: PublicKey, authority: Keypair) =>
+    program.methods
+      .archiveMonitoringData(otherCid)
+      .accounts({
+        config: configPda,
+        monitoringData,
+        registry: registryPda,
+        authority: authority.publicKey,
+      } as any)
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+  });
+
+  it("Deregisters a project without credits and frees its ID", async () => {
+    const projectId = uniqueId("DEREG");
+    const project = await registerProject(owner, projectId);
+    const { totalProjects } = await program.account.globalRegistry.fetch(registryPda);
+    const balanceBefore = await provider.connection.getBalance(owner.publicKey);
+
+    await deregister(project, projectId);
+
+    assert.isNull(await program.account.project.fetchNullable(project));
+    assert.isNull(await program.account.projectIdIndex.fetchNullable(projectIndexPda(projectId)));
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.equal(registry.totalProjects.toString(), totalProjects.subn(1).toString());
+    // Both accounts' rent came back to the owner
+    assert.isAbove(await provider.connection.getBalance(owner.publicKey), balanceBefore);
+
+    // Anyone may now register the ID
+    const newOwner = await fundedKeypair();
+    await registerProject(newOwner, projectId);
+
+    console.log("✅ Project deregistered successfully");
+  });
+
+  it("Keeps projects that issued credits, and only the owner can deregister", async () => {
+    const projectId = uniqueId("DEREG");
+    const project = await registerProject(owner, projectId);
+
+    await expectError(deregister(project, projectId, await fundedKeypair()), "ConstraintHasOne");
+
+    await submitForReview(owner, project);
+    await verifyProject(project);
+    await mintCredits(owner, project, 1);
+
+    await expectError(deregister(project, projectId), "ProjectHasCredits");
+    assert.isNotNull(await program.account.project.fetchNullable(project));
+  });
+
+  it("Archives monitoring data as an auditor", async () => {
+    const projectId = uniqueId("ARCH");
+    const project = await registerProject(owner, projectId);
+    const monitoringData = await submitMonitoring(admin, project, projectId);
+
+    await expectError(archive(monitoringData, await fundedKeypair()), "MissingRole");
+
+    // The registry admin holds the auditor role in single-key mode
+    await archive(monitoringData, admin);
+    assert.isNull(await program.account.monitoringData.fetchNullable(monitoringData));
+  });
+});
@@ -0,0 +1,109 @@
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  configPda,
+  ipfsCid,
+  otherCid,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("project documents", () => {
+  let owner: Keypair;
+  let project: PublicKey;
+
+  const contentHash = (content: string) => Array.from(createHash("sha256").update(content).digest());
+
+  const documentPda = (hash: number[]) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("document"), project.toBuffer(), Buffer.from(hash)],
+      program.programId
+    )[0];
+
+  const addDocument = async (
+    kind: string,
+    hash: number[],
+    supersedes: PublicKey | null = null,
+    uploader = owner,
+    cid = ipfsCid
+  ) => {
+    const document = documentPda(hash);
+    await program.methods
+      .addProjectDocument({ [kind]: {} } as any, cid, hash)
+      .accounts({
+        config: configPda,
+        project,
+        document,
+        supersedes,
+        registry: registryPda,
+        uploader: uploader.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([uploader])
+      .rpc();
+    return document;
+  };
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+    project = await registerProject(owner, uniqueId("DOCS"));
+  });
+
+  it("Adds a document and links new versions to it", async () => {
+    const hash = contentHash("design v1");
+    const first = await addDocument("projectDesign", hash);
+
+    let document = await program.account.projectDocument.fetch(first);
+    assert.equal(document.project.toString(), project.toString());
+    assert.deepEqual(document.kind, { projectDesign: {} });
+    assert.equal(document.cid, ipfsCid);
+    assert.deepEqual(document.contentHash, hash);
+    assert.equal(document.uploader.toString(), owner.publicKey.toString());
+    assert.isNull(document.supersedes);
+
+    const second = await addDocument("projectDesign", contentHash("design v2"), first, owner, otherCid);
+    document = await program.account.projectDocument.fetch(second);
+    assert.equal(document.supersedes.toString(), first.toString());
+    const previous = await program.account.projectDocument.fetch(first);
+    assert.equal(previous.supersededBy.toString(), second.toString());
+
+    // Only the latest version can be replaced, and only by the same kind
+    await expectError(
+      addDocument("projectDesign", contentHash("design v2b"), first),
+      "DocumentAlreadySuperseded"
+    );
+    await expectError(
+      addDocument("permit", contentHash("permit v1"), second),
+      "DocumentKindMismatch"
+    );
+
+    console.log("✅ Project documents added successfully");
+  });
+
+  it("Rejects documents without a content hash or from outsiders", async () => {
+    await expectError(addDocument("permit", new Array(32).fill(0)), "InvalidContentHash");
+    await expectError(
+      addDocument("permit", contentHash("forged permit"), null, await fundedKeypair()),
+      "NotProjectOwner"
+    );
+  });
+
+  it("Takes verification reports from verifiers only", async () => {
+    await expectError(
+      addDocument("verificationReport", contentHash("self-verified")),
+      "MissingRole"
+    );
+
+    const report = await addDocument("verificationReport", contentHash("report"), null, admin);
+    const document = await program.account.projectDocument.fetch(report);
+    assert.equal(document.uploader.toString(), admin.publicKey.toString());
+  });
+});
@@ -0,0 +1,390 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { BlueCarbonRegistry } from "../target/types/blue_carbon_registry";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+
+// Shared setup for the test files. Mocha loads them in name order and they
+// all run against the same registry, so each file calls ensureRegistry()
+// instead of relying on blue-carbon-registry.ts having run first.
+
+export const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+export const program = anchor.workspace.BlueCarbonRegistry as Program<BlueCarbonRegistry>;
+
+// The provider wallet initializes the registry and stays its admin
+export const admin = (provider.wallet as anchor.Wallet).payer;
+
+export const TOKEN_DECIMALS = 6;
+export const ipfsCid = "QmYwAPJzv5CZsnAzt8auVKRQm6VLw4Dy8YQANhBBfmGjw8";
+export const otherCid = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+const pda = (...seeds: Buffer[]) =>
+  PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+export const registryPda = pda(Buffer.from("registry_v3"));
+export const configPda = pda(Buffer.from("registry_config"));
+export const tokenMint = pda(Buffer.from("carbon_token_mint_v3"));
+export const bufferPoolPda = pda(Buffer.from("buffer_pool"));
+
+// Role discriminants, as used in the role assignment seeds
+export const ROLE = {
+  admin: 0,
+  verifier: 1,
+  dataProvider: 2,
+  marketplaceOperator: 3,
+  auditor: 4,
+};
+
+export const rolePda = (holder: PublicKey, role: number) =>
+  pda(Buffer.from("role"), holder.toBuffer(), Buffer.from([role]));
+
+export const projectPda = (owner: PublicKey, projectId: string) =>
+  pda(Buffer.from("project"), owner.toBuffer(), Buffer.from(projectId));
+
+export const projectIndexPda = (projectId: string) =>
+  pda(Buffer.from("project_id"), Buffer.from(projectId));
+
+export const amendmentPda = (project: PublicKey, index: number) => {
+  const seed = Buffer.alloc(4);
+  seed.writeUInt32LE(index);
+  return pda(Buffer.from("amendment"), project.toBuffer(), seed);
+};
+
+// Fixed-point arguments are passed as their raw inner value
+export const measure = (value: number) => [new anchor.BN(Math.round(value * 1_000_000))] as any;
+export const tonnes = (value: number) => [new anchor.BN(Math.round(value * 1_000_000))] as any;
+export const hectares = (value: number) => [new anchor.BN(Math.round(value * 10_000))] as any;
+export const basisPoints = (value: number) => [Math.round(value * 100)] as any;
+export const degrees = (value: number) => [Math.round(value * 10_000_000)] as any;
+
+// Builds an instruction argument of IDL type `typeName` from snake_case
+// field names, taking the client's camelCase spelling from the IDL itself
+// (soil_carbon_0_30cm is easy to get wrong by hand)
+export function idlStruct(typeName: string, fields: { [name: string]: unknown }) {
+  const normalize = (name: string) => name.replace(/_/g, "").toLowerCase();
+  const type = program.idl.types.filter((t) => normalize(t.name) === normalize(typeName))[0];
+  const names: string[] = (type.type as any).fields.map((field: any) => field.name);
+  const struct: { [name: string]: unknown } = {};
+  for (const key of Object.keys(fields)) {
+    const name = names.filter((candidate) => normalize(candidate) === normalize(key))[0];
+    assert.isDefined(name, `${typeName} has no field ${key}`);
+    struct[name] = fields[key];
+  }
+  return struct as any;
+}
+
+// A square site `size` degrees across with its south-west corner at
+// `longitude`, `latitude`, as a GeoLocation
+export function squareSite(longitude: number, latitude: number, size = 0.01) {
+  const raw = (value: number) => Math.round(value * 10_000_000);
+  const ring = [
+    [longitude, latitude],
+    [longitude + size, latitude],
+    [longitude + size, latitude + size],
+    [longitude, latitude + size],
+    [longitude, latitude],
+  ].map(([lon, lat]) => [raw(lon), raw(lat)]);
+  return {
+    latitude: [ring[0][1]],
+    longitude: [ring[0][0]],
+    polygonCoordinates: ring.map(([lon, lat]) => [[lon], [lat]]),
+    countryCode: "ID",
+    regionName: "Test site",
+  };
+}
+
+// Area of a 0.01° `squareSite` on the equator
+export const SITE_AREA_HECTARES = 123.09;
+
+// Somewhere on the equator between `from` and `from + 40` degrees east, so
+// reruns against the same validator do not overlap earlier sites
+export const randomLongitude = (from: number) => from + Math.floor(Math.random() * 800) * 0.05;
+
+// Port of geo::covering_cells: the precision-5 geohashes of every cell the
+// ring's bounding box touches, south-west to north-east
+const GEOHASH_ALPHABET = "0123456789bcdefghjkmnpqrstuvwxyz";
+const LON_BITS = 13;
+const LAT_BITS = 12;
+
+function cellIndex(value: number, halfRange: number, bits: number) {
+  const cells = 2 ** bits;
+  const min = -halfRange * 10_000_000;
+  const span = 2 * halfRange * 10_000_000;
+  const index = Math.floor(((value - min) * cells) / span);
+  return Math.min(Math.max(index, 0), cells - 1);
+}
+
+function encodeGeohash(lonIndex: number, latIndex: number) {
+  let bits = 0;
+  for (let i = 0; i < LON_BITS + LAT_BITS; i++) {
+    const bit =
+      i % 2 === 0
+        ? (lonIndex >> (LON_BITS - 1 - i / 2)) & 1
+        : (latIndex >> (LAT_BITS - 1 - (i - 1) / 2)) & 1;
+    bits = bits * 2 + bit;
+  }
+  let geohash = "";
+  for (let c = 4; c >= 0; c--) {
+    geohash += GEOHASH_ALPHABET[(bits >> (5 * c)) & 31];
+  }
+  return geohash;
+}
+
+export function coveringCells(location: { polygonCoordinates: number[][][] }) {
+  const lons = location.polygonCoordinates.map(([[lon]]) => lon);
+  const lats = location.polygonCoordinates.map(([, [lat]]) => lat);
+  const cells: string[] = [];
+  for (let lat = cellIndex(Math.min(...lats), 90, LAT_BITS); lat <= cellIndex(Math.max(...lats), 90, LAT_BITS); lat++) {
+    for (let lon = cellIndex(Math.min(...lons), 180, LON_BITS); lon <= cellIndex(Math.max(...lons), 180, LON_BITS); lon++) {
+      cells.push(encodeGeohash(lon, lat));
+    }
+  }
+  return cells;
+}
+
+// Remaining accounts for registering `location`: its geo cells, then the
+// projects already listed in them
+export function spatialIndexAccounts(
+  location: { polygonCoordinates: number[][][] },
+  neighbours: PublicKey[] = []
+) {
+  return [
+    ...coveringCells(location).map((geohash) => ({
+      pubkey: pda(Buffer.from("geo_cell"), Buffer.from(geohash)),
+      isWritable: true,
+      isSigner: false,
+    })),
+    ...neighbours.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })),
+  ];
+}
+
+// Project IDs are globally unique and at most 32 bytes
+let idCounter = 0;
+export const uniqueId = (prefix: string) =>
+  `${prefix}-${Date.now().toString(36)}-${(idCounter++).toString(36)}`;
+
+export async function ensureRegistry() {
+  if (!(await provider.connection.getAccountInfo(registryPda))) {
+    await program.methods
+      .initializeRegistry(TOKEN_DECIMALS)
+      .accounts({
+        registry: registryPda,
+        carbonTokenMint: tokenMint,
+        admin: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      } as any)
+      .signers([admin])
+      .rpc();
+  }
+  if (!(await provider.connection.getAccountInfo(configPda))) {
+    await program.methods
+      .initializeRegistryConfig()
+      .accounts({
+        config: configPda,
+        registry: registryPda,
+        carbonTokenMint: tokenMint,
+        bufferPool: bufferPoolPda,
+        admin: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+  }
+}
+
+export async function fundedKeypair(sol = 2) {
+  const keypair = Keypair.generate();
+  const signature = await provider.connection.requestAirdrop(
+    keypair.publicKey,
+    sol * LAMPORTS_PER_SOL
+  );
+  await provider.connection.confirmTransaction(signature);
+  return keypair;
+}
+
+// Registers a minimal project through the legacy register_project
+export async function registerProject(owner: Keypair, projectId: string, tons = 1000) {
+  const project = projectPda(owner.publicKey, projectId);
+  await program.methods
+    .registerProject(projectId, ipfsCid, new anchor.BN(tons))
+    .accounts({
+      config: configPda,
+      project,
+      projectIndex: projectIndexPda(projectId),
+      registry: registryPda,
+      projectOwner: owner.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any)
+    .signers([owner])
+    .rpc();
+  return project;
+}
+
+export async function submitForReview(owner: Keypair, project: PublicKey) {
+  await program.methods
+    .submitForReview()
+    .accounts({
+      config: configPda,
+      project,
+      registry: registryPda,
+      authority: owner.publicKey,
+    } as any)
+    .signers([owner])
+    .rpc();
+}
+
+// Verifies with the registry admin, who holds every role in single-key
+// mode, unless another verifier and its role assignment are given
+export async function verifyProject(
+  project: PublicKey,
+  tons = 800,
+  verifier = admin,
+  verifierRole: PublicKey | null = null
+) {
+  await program.methods
+    .verifyProject(new anchor.BN(tons))
+    .accounts({
+      config: configPda,
+      project,
+      registry: registryPda,
+      adminRole: verifierRole,
+      admin: verifier.publicKey,
+    } as any)
+    .signers([verifier])
+    .rpc();
+}
+
+// Mints whole tokens of the current vintage to the owner's token account
+export async function mintCredits(
+  owner: Keypair,
+  project: PublicKey,
+  tokens: number,
+  proponentProposal: PublicKey | null = null
+) {
+  const recipientTokenAccount = (
+    await getOrCreateAssociatedTokenAccount(provider.connection, owner, tokenMint, owner.publicKey)
+  ).address;
+  await program.methods
+    .mintVerifiedCredits(
+      new anchor.BN(tokens).mul(new anchor.BN(10 ** TOKEN_DECIMALS)),
+      new Date().getUTCFullYear()
+    )
+    .accounts({
+      config: configPda,
+      project,
+      registry: registryPda,
+      carbonTokenMint: tokenMint,
+      mintAuthority: registryPda,
+      recipientTokenAccount,
+      bufferPool: bufferPoolPda,
+      proponentProposal,
+      methodology: null,
+      owner: owner.publicKey,
+      recipient: owner.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    } as any)
+    .signers([owner])
+    .rpc();
+  return recipientTokenAccount;
+}
+
+export async function submitMonitoring(
+  dataProvider: Keypair,
+  project: PublicKey,
+  projectId: string,
+  timestamp = new anchor.BN(Math.floor(Date.now() / 1000))
+) {
+  const measure = (value: number) => [new anchor.BN(Math.round(value * 1_000_000))] as any;
+  const monitoringData = pda(
+    Buffer.from("monitoring"),
+    Buffer.from(projectId),
+    timestamp.toArrayLike(Buffer, "le", 8)
+  );
+  await program.methods
+    .submitMonitoringData(projectId, timestamp, {
+      projectId,
+      satelliteImageryCid: ipfsCid,
+      ndviIndex: measure(0.5),
+      waterQuality: {
+        phLevel: measure(8),
+        salinity: measure(30),
+        dissolvedOxygen: measure(6),
+        turbidity: measure(3),
+        nutrients: {
+          nitrogen: measure(0.4),
+          phosphorus: measure(0.05),
+          potassium: measure(0.3),
+        },
+      },
+      temperatureData: [],
+      tideData: [],
+      iotSensorData: [],
+      ecosystemHealthScore: measure(80),
+    } as any)
+    .accounts({
+      config: configPda,
+      monitoringData,
+      project,
+      projectIndex: projectIndexPda(projectId),
+      registry: registryPda,
+      dataProvider: dataProvider.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any)
+    .signers([dataProvider])
+    .rpc();
+  return monitoringData;
+}
+
+export async function grantRole(holder: PublicKey, role: number) {
+  const roleAssignment = rolePda(holder, role);
+  const roleArg = Object.keys(ROLE)[role];
+  await program.methods
+    .grantRole(holder, { [roleArg]: {} } as any)
+    .accounts({
+      roleAssignment,
+      registry: registryPda,
+      authority: admin.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any)
+    .signers([admin])
+    .rpc();
+  return roleAssignment;
+}
+
+export async function revokeRole(roleAssignment: PublicKey) {
+  await program.methods
+    .revokeRole()
+    .accounts({
+      roleAssignment,
+      registry: registryPda,
+      authority: admin.publicKey,
+    } as any)
+    .signers([admin])
+    .rpc();
+}
+
+// Asserts that `promise` fails with the program error `code`
+export async function expectError(promise: Promise<unknown>, code: string) {
+  try {
+    await promise;
+  } catch (error: any) {
+    assert.include(error.toString(), code);
+    return;
+  }
+  assert.fail(`Expected the transaction to fail with ${code}`);
+}
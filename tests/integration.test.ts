@@ -125,7 +125,7 @@ describe("Blue Carbon Registry - Integration Tests", () => {
       );
       
       const tx = await program.methods
-        .registerProject(PROJECT_ID, "QmTest123...", CARBON_ESTIMATE)
+        .registerProject(PROJECT_ID, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG", CARBON_ESTIMATE)
         .accounts({
           project: projectPda,
           registry: registryPda,
@@ -155,7 +155,7 @@ describe("Blue Carbon Registry - Integration Tests", () => {
         );
         
         await program.methods
-          .registerProject(PROJECT_ID, "QmTest456...", new anchor.BN(500))
+          .registerProject(PROJECT_ID, "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi", new anchor.BN(500))
           .accounts({
             project: dupProjectPda,
             registry: registryPda,
@@ -189,6 +189,21 @@ describe("Blue Carbon Registry - Integration Tests", () => {
       );
     });
     
+    it("Should submit the project for review", async () => {
+      await program.methods
+        .submitForReview()
+        .accounts({
+          project: projectPda,
+          registry: registryPda,
+          authority: projectOwner.publicKey,
+        } as any)
+        .signers([projectOwner])
+        .rpc();
+
+      const project = await program.account.project.fetch(projectPda);
+      assert.ok(project.verificationStatus.underReview !== undefined);
+    });
+
     it("Should verify a project (validator approval)", async () => {
       const verifiedCarbonTons = CARBON_ESTIMATE;
       
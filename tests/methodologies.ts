@@ -0,0 +1,178 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  configPda,
+  projectPda,
+  projectIndexPda,
+  ipfsCid,
+  otherCid,
+  measure,
+  tonnes,
+  hectares,
+  basisPoints,
+  idlStruct,
+  squareSite,
+  randomLongitude,
+  spatialIndexAccounts,
+  SITE_AREA_HECTARES,
+  ensureRegistry,
+  fundedKeypair,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("methodologies", () => {
+  let owner: Keypair;
+  let methodology: PublicKey;
+  const longitude = randomLongitude(60);
+  // Each registration gets its own site a tenth of a degree further east
+  let sites = 0;
+
+  const methodologyPda = (methodologyId: string, methodologyVersion: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("methodology"), Buffer.from(methodologyId), Buffer.from(methodologyVersion)],
+      program.programId
+    )[0];
+
+  const registerMethodology = async (authority: Keypair) => {
+    // Published versions are permanent, so reruns need a fresh one
+    const methodologyVersion = uniqueId("v");
+    const account = methodologyPda("VM0033", methodologyVersion);
+    await program.methods
+      .registerMethodology({
+        methodologyId: "VM0033",
+        methodologyVersion,
+        documentCid: ipfsCid,
+        eligibleEcosystems: [{ mangrove: {} }, { seagrass: {} }],
+        emissionFactors: [
+          {
+            ecosystemType: { mangrove: {} },
+            aboveGroundBiomass: tonnes(100),
+            belowGroundBiomass: tonnes(40),
+            soilCarbon: tonnes(140),
+            sequestrationRateAnnual: tonnes(5),
+          },
+        ],
+        monitoringIntervalDays: 365,
+        bufferPercentage: basisPoints(15),
+        maxUncertainty: basisPoints(20),
+        uncertaintyAllowance: basisPoints(10),
+        maxLeakage: basisPoints(10),
+      } as any)
+      .accounts({
+        config: configPda,
+        methodology: account,
+        registry: registryPda,
+        proposal: null,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([authority])
+      .rpc();
+    return account;
+  };
+
+  const projectData = (ecosystem: string, uncertainty: number) =>
+    idlStruct("BlueProjectData", {
+      project_id: uniqueId("METH"),
+      ipfs_cid: ipfsCid,
+      carbon_tons_estimated: new anchor.BN(500),
+      ecosystem_type: { [ecosystem]: {} },
+      location: squareSite(longitude + 0.1 * sites++, 0),
+      area_hectares: hectares(SITE_AREA_HECTARES),
+      species_composition: ["Rhizophora mucronata"],
+      biodiversity_index: measure(0.7),
+      above_ground_biomass: tonnes(100),
+      below_ground_biomass: tonnes(40),
+      soil_carbon_0_30cm: tonnes(60),
+      soil_carbon_30_100cm: tonnes(80),
+      sequestration_rate_annual: tonnes(5),
+      measurement_methodology: "IPCC 2013 Wetlands Supplement",
+      uncertainty_percentage: basisPoints(uncertainty),
+      vcs_methodology: "VM0033",
+      additionality_proof_cid: otherCid,
+      permanence_guarantee_years: 30,
+      leakage_assessment: basisPoints(5),
+      monitoring_plan_cid: otherCid,
+      baseline_ecosystem_health: measure(60),
+      species_count_baseline: 12,
+      co_benefits: [],
+      vintage_year: new Date().getUTCFullYear(),
+      price_per_ton: new anchor.BN(15),
+    });
+
+  const registerUnder = async (data: any) => {
+    const project = projectPda(owner.publicKey, data.projectId);
+    await program.methods
+      .registerBlueCarbonProject(data)
+      .accounts({
+        config: configPda,
+        project,
+        projectIndex: projectIndexPda(data.projectId),
+        methodology,
+        registry: registryPda,
+        projectOwner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(spatialIndexAccounts(data.location))
+      .signers([owner])
+      .rpc();
+    return project;
+  };
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair(5);
+  });
+
+  it("Publishes methodologies from the registry admin only", async () => {
+    await expectError(registerMethodology(await fundedKeypair()), "Unauthorized");
+
+    methodology = await registerMethodology(admin);
+    const account = await program.account.methodology.fetch(methodology);
+    assert.equal(account.methodologyId, "VM0033");
+    assert.isTrue(account.active);
+    assert.equal(account.eligibleEcosystems.length, 2);
+    assert.equal(account.maxUncertainty[0], 2_000);
+
+    console.log("✅ Methodology registered successfully");
+  });
+
+  it("Registers projects that conform to the methodology", async () => {
+    const project = await registerUnder(projectData("mangrove", 10));
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.methodology.toString(), methodology.toString());
+
+    console.log("✅ Project registered under methodology successfully");
+  });
+
+  it("Rejects projects outside the methodology", async () => {
+    await expectError(registerUnder(projectData("saltMarsh", 10)), "MethodologyNotConformant");
+    await expectError(registerUnder(projectData("mangrove", 25)), "MethodologyNotConformant");
+  });
+
+  it("Closes a deprecated methodology to new projects", async () => {
+    const deprecate = (authority: Keypair) =>
+      program.methods
+        .deprecateMethodology()
+        .accounts({
+          methodology,
+          registry: registryPda,
+          proposal: null,
+          authority: authority.publicKey,
+        } as any)
+        .signers([authority])
+        .rpc();
+
+    await expectError(deprecate(await fundedKeypair()), "Unauthorized");
+    await deprecate(admin);
+    assert.isFalse((await program.account.methodology.fetch(methodology)).active);
+    await expectError(deprecate(admin), "MethodologyInactive");
+
+    await expectError(registerUnder(projectData("mangrove", 10)), "MethodologyInactive");
+  });
+});
@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  ROLE,
+  rolePda,
+  ensureRegistry,
+  fundedKeypair,
+  grantRole,
+  revokeRole,
+  expectError,
+} from "./helpers";
+
+describe("admin multisig", () => {
+  let second: Keypair;
+  let third: Keypair;
+  // Verifier granted by proposal, revoked once back in single-key mode
+  let granted: PublicKey;
+  const multisigPda = PublicKey.findProgramAddressSync([Buffer.from("admin_multisig")], program.programId)[0];
+
+  const proposalPda = (index: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_proposal"), index.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const propose = async (proposer: Keypair, action: object) => {
+    const { adminProposalCount } = await program.account.globalRegistry.fetch(registryPda);
+    const proposal = proposalPda(adminProposalCount);
+    await program.methods
+      .createAdminProposal(action as any)
+      .accounts({
+        proposal,
+        registry: registryPda,
+        multisig: multisigPda,
+        proposer: proposer.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([proposer])
+      .rpc();
+    return proposal;
+  };
+
+  const approve = (proposal: PublicKey, approver: Keypair) =>
+    program.methods
+      .approveAdminProposal()
+      .accounts({ proposal, registry: registryPda, multisig: multisigPda, approver: approver.publicKey } as any)
+      .signers([approver])
+      .rpc();
+
+  const grantWithProposal = (holder: PublicKey, role: number, proposal: PublicKey, executor: Keypair) =>
+    program.methods
+      .grantRole(holder, { [Object.keys(ROLE)[role]]: {} } as any)
+      .accounts({
+        roleAssignment: rolePda(holder, role),
+        registry: registryPda,
+        authorityRole: null,
+        proposal,
+        authority: executor.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([executor])
+      .rpc();
+
+  const disable = async () => {
+    const proposal = await propose(second, { disableMultisig: {} });
+    await approve(proposal, third);
+    await program.methods
+      .disableAdminMultisig()
+      .accounts({ multisig: multisigPda, registry: registryPda, proposal, executor: second.publicKey } as any)
+      .signers([second])
+      .rpc();
+  };
+
+  before(async () => {
+    await ensureRegistry();
+    second = await fundedKeypair();
+    third = await fundedKeypair();
+    await program.methods
+      .enableAdminMultisig([admin.publicKey, second.publicKey, third.publicKey], 2)
+      .accounts({
+        multisig: multisigPda,
+        registry: registryPda,
+        admin: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([admin])
+      .rpc();
+  });
+
+  // Later test files run against a single-key registry
+  after(async () => {
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    if (registry.multisigEnabled) {
+      await disable();
+    }
+  });
+
+  it("Rejects the implicit admin once multisig is enabled", async () => {
+    const holder = Keypair.generate();
+    await expectError(grantRole(holder.publicKey, ROLE.verifier), "MultisigApprovalRequired");
+
+    // Nor does the admin key hold every role any more
+    const trade = program.methods
+      .tradeCredits(new anchor.BN(1))
+      .accounts({ registry: registryPda, authorityRole: null, authority: admin.publicKey } as any)
+      .signers([admin])
+      .rpc();
+    await expectError(trade, "MissingRole");
+  });
+
+  it("Executes a proposal only with enough approvals, by an approver, for the same action", async () => {
+    const holder = Keypair.generate();
+    const proposal = await propose(second, { grantRole: { holder: holder.publicKey, role: { verifier: {} } } });
+
+    // The proposer's approval alone is one short of the threshold
+    await expectError(grantWithProposal(holder.publicKey, ROLE.verifier, proposal, second), "InsufficientApprovals");
+
+    await approve(proposal, third);
+    await expectError(approve(proposal, third), "AlreadyApproved");
+    await expectError(approve(proposal, await fundedKeypair()), "NotMultisigMember");
+
+    // The admin is a member, but did not approve this proposal
+    await expectError(grantWithProposal(holder.publicKey, ROLE.verifier, proposal, admin), "NotMultisigMember");
+    // The approvals cover this holder and role only
+    await expectError(grantWithProposal(holder.publicKey, ROLE.auditor, proposal, second), "ProposalActionMismatch");
+
+    await grantWithProposal(holder.publicKey, ROLE.verifier, proposal, third);
+    const assignment = await program.account.roleAssignment.fetch(rolePda(holder.publicKey, ROLE.verifier));
+    assert.equal(assignment.holder.toString(), holder.publicKey.toString());
+    assert.isTrue((await program.account.adminProposal.fetch(proposal)).executed);
+    granted = rolePda(holder.publicKey, ROLE.verifier);
+
+    console.log("✅ Multisig proposal executed successfully");
+  });
+
+  it("Invalidates open proposals when the key set changes", async () => {
+    const holder = Keypair.generate();
+    const stale = await propose(second, { grantRole: { holder: holder.publicKey, role: { verifier: {} } } });
+
+    // Same members, same threshold, but a new key set nonetheless
+    const members = [admin.publicKey, second.publicKey, third.publicKey];
+    const update = await propose(second, { updateMultisig: { members, threshold: 2 } });
+    await approve(update, third);
+    await program.methods
+      .updateAdminMultisig(members, 2)
+      .accounts({ multisig: multisigPda, registry: registryPda, proposal: update, executor: third.publicKey } as any)
+      .signers([third])
+      .rpc();
+
+    await expectError(approve(stale, third), "StaleProposal");
+    await expectError(grantWithProposal(holder.publicKey, ROLE.verifier, stale, second), "StaleProposal");
+  });
+
+  it("Returns to single-key mode by proposal", async () => {
+    await disable();
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.isFalse(registry.multisigEnabled);
+
+    // The admin key holds every role again
+    const holder = Keypair.generate();
+    const assignment = await grantRole(holder.publicKey, ROLE.verifier);
+    await revokeRole(assignment);
+    await revokeRole(granted);
+  });
+});
@@ -0,0 +1,75 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("pause", () => {
+  let owner: Keypair;
+
+  const setPause = (subsystem: string, paused: boolean, authority = admin) =>
+    program.methods
+      .setPause({ [subsystem]: {} } as any, paused)
+      .accounts({ registry: registryPda, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+  });
+
+  // Leave every subsystem running for the other test files
+  after(async () => {
+    for (const subsystem of ["registration", "verification", "minting", "transfers", "marketplace"]) {
+      await setPause(subsystem, false);
+    }
+  });
+
+  it("Pauses one subsystem without stopping the others", async () => {
+    await setPause("registration", true);
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.notEqual(registry.pausedSubsystems, 0);
+
+    await expectError(registerProject(owner, uniqueId("PAUSED")), "RegistrationPaused");
+
+    // The marketplace keeps running
+    await program.methods
+      .tradeCredits(new anchor.BN(1))
+      .accounts({ registry: registryPda, authority: admin.publicKey } as any)
+      .signers([admin])
+      .rpc();
+
+    await setPause("registration", false);
+    await registerProject(owner, uniqueId("RESUMED"));
+
+    console.log("✅ Registration paused and resumed successfully");
+  });
+
+  it("Pauses the marketplace", async () => {
+    await setPause("marketplace", true);
+    await expectError(
+      program.methods
+        .tradeCredits(new anchor.BN(1))
+        .accounts({ registry: registryPda, authority: admin.publicKey } as any)
+        .signers([admin])
+        .rpc(),
+      "MarketplacePaused"
+    );
+    await setPause("marketplace", false);
+  });
+
+  it("Only an admin can pause", async () => {
+    await expectError(setPause("minting", true, Keypair.generate()), "MissingRole");
+    const registry = await program.account.globalRegistry.fetch(registryPda);
+    assert.equal(registry.pausedSubsystems, 0);
+  });
+});
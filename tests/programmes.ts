@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  configPda,
+  projectPda,
+  projectIndexPda,
+  ipfsCid,
+  otherCid,
+  measure,
+  tonnes,
+  hectares,
+  basisPoints,
+  idlStruct,
+  squareSite,
+  randomLongitude,
+  spatialIndexAccounts,
+  SITE_AREA_HECTARES,
+  ensureRegistry,
+  fundedKeypair,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("programmes", () => {
+  let owner: Keypair;
+  let programme: PublicKey;
+  // Sites so far, passed as overlap candidates for the cells they share
+  const sites: PublicKey[] = [];
+  const longitude = randomLongitude(10);
+
+  const siteData = (projectId: string, location: object, area = SITE_AREA_HECTARES, uncertainty = 10) =>
+    idlStruct("ProgrammeSiteData", {
+      project_id: projectId,
+      ipfs_cid: ipfsCid,
+      carbon_tons_estimated: new anchor.BN(500),
+      location,
+      area_hectares: hectares(area),
+      species_composition: ["Rhizophora mucronata"],
+      biodiversity_index: measure(0.7),
+      above_ground_biomass: tonnes(100),
+      below_ground_biomass: tonnes(40),
+      soil_carbon_0_30cm: tonnes(60),
+      soil_carbon_30_100cm: tonnes(80),
+      sequestration_rate_annual: tonnes(5),
+      uncertainty_percentage: basisPoints(uncertainty),
+      leakage_assessment: basisPoints(5),
+      baseline_ecosystem_health: measure(60),
+      species_count_baseline: 12,
+      co_benefits: [],
+      vintage_year: new Date().getUTCFullYear(),
+      price_per_ton: new anchor.BN(15),
+    });
+
+  const addSite = async (site: any) => {
+    const project = projectPda(owner.publicKey, site.projectId);
+    await program.methods
+      .addProgrammeSite(site)
+      .accounts({
+        config: configPda,
+        programme,
+        project,
+        projectIndex: projectIndexPda(site.projectId),
+        registry: registryPda,
+        owner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(spatialIndexAccounts(site.location, sites))
+      .signers([owner])
+      .rpc();
+    sites.push(project);
+    return project;
+  };
+
+  const validate = (verifier: Keypair) =>
+    program.methods
+      .validateProgramme()
+      .accounts({ programme, registry: registryPda, verifier: verifier.publicKey } as any)
+      .signers([verifier])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair(5);
+
+    const programmeId = uniqueId("PRG");
+    programme = PublicKey.findProgramAddressSync(
+      [Buffer.from("programme"), owner.publicKey.toBuffer(), Buffer.from(programmeId)],
+      program.programId
+    )[0];
+    await program.methods
+      .registerProgramme({
+        programmeId,
+        designCid: ipfsCid,
+        ecosystemType: { mangrove: {} },
+        vcsMethodology: "VM0033",
+        measurementMethodology: "IPCC 2013 Wetlands Supplement",
+        additionalityProofCid: otherCid,
+        monitoringPlanCid: otherCid,
+        permanenceGuaranteeYears: 30,
+        eligibility: {
+          countryCodes: ["ID"],
+          boundary: [],
+          minSiteArea: hectares(50),
+          maxSiteArea: hectares(500),
+          maxUncertainty: basisPoints(20),
+          maxLeakage: basisPoints(10),
+        },
+      } as any)
+      .accounts({
+        config: configPda,
+        programme,
+        registry: registryPda,
+        owner: owner.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([owner])
+      .rpc();
+  });
+
+  it("Onboards sites only once a verifier validates the programme", async () => {
+    const early = siteData(uniqueId("SITE"), squareSite(longitude, 0));
+    await expectError(addSite(early), "ProgrammeNotValidated");
+
+    await expectError(validate(Keypair.generate()), "MissingRole");
+    await validate(admin);
+    await expectError(validate(admin), "ProgrammeAlreadyValidated");
+
+    const project = await addSite(early);
+    const site = await program.account.project.fetch(project);
+    assert.equal(site.programme.toString(), programme.toString());
+    // The design comes from the programme
+    assert.deepEqual(site.ecosystemType, { mangrove: {} });
+    assert.equal(site.vcsMethodology, "VM0033");
+    assert.equal(site.permanenceGuaranteeYears, 30);
+
+    const programmeAccount = await program.account.programme.fetch(programme);
+    assert.equal(programmeAccount.validatedBy.toString(), admin.publicKey.toString());
+    assert.equal(programmeAccount.siteCount, 1);
+    assert.equal(programmeAccount.totalAreaHectares[0].toString(), site.areaHectares[0].toString());
+
+    console.log("✅ Programme site onboarded successfully");
+  });
+
+  it("Rejects sites outside the eligibility criteria", async () => {
+    // Three times wider: nine times the area, over the 500 ha limit
+    const large = squareSite(longitude + 0.1, 0, 0.03);
+    await expectError(
+      addSite(siteData(uniqueId("SITE"), large, SITE_AREA_HECTARES * 9)),
+      "SiteNotEligible"
+    );
+
+    const uncertain = siteData(uniqueId("SITE"), squareSite(longitude + 0.3, 0), SITE_AREA_HECTARES, 30);
+    await expectError(addSite(uncertain), "SiteNotEligible");
+
+    const abroad = squareSite(longitude + 0.4, 0);
+    abroad.countryCode = "PH";
+    await expectError(addSite(siteData(uniqueId("SITE"), abroad)), "SiteNotEligible");
+
+    const programmeAccount = await program.account.programme.fetch(programme);
+    assert.equal(programmeAccount.siteCount, 1);
+  });
+});
@@ -0,0 +1,53 @@
+import { Keypair } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  projectPda,
+  projectIndexPda,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitMonitoring,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("project IDs", () => {
+  let firstOwner: Keypair;
+  let secondOwner: Keypair;
+
+  before(async () => {
+    await ensureRegistry();
+    firstOwner = await fundedKeypair();
+    secondOwner = await fundedKeypair();
+  });
+
+  it("Reserves a project ID for the first project to register it", async () => {
+    const projectId = uniqueId("ID");
+    const project = await registerProject(firstOwner, projectId);
+
+    const index = await program.account.projectIdIndex.fetch(projectIndexPda(projectId));
+    assert.equal(index.projectId, projectId);
+    assert.equal(index.project.toString(), project.toString());
+
+    // The same ID under another owner would be a second project PDA, but
+    // the ID reservation already exists
+    await expectError(registerProject(secondOwner, projectId), "already in use");
+    assert.isNull(
+      await program.account.project.fetchNullable(projectPda(secondOwner.publicKey, projectId))
+    );
+
+    console.log("✅ Project ID reserved successfully");
+  });
+
+  it("Rejects records filed under another project's ID", async () => {
+    const firstId = uniqueId("ID");
+    const secondId = uniqueId("ID");
+    await registerProject(firstOwner, firstId);
+    const secondProject = await registerProject(secondOwner, secondId);
+
+    // Filed as firstId, but against the second project
+    await expectError(submitMonitoring(admin, secondProject, firstId), "ProjectIdMismatch");
+  });
+});
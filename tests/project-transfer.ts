@@ -0,0 +1,77 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("project ownership transfer", () => {
+  let owner: Keypair;
+  let buyer: Keypair;
+
+  const propose = (project: PublicKey, authority: Keypair, newOwner: PublicKey) =>
+    program.methods
+      .proposeProjectTransfer(newOwner)
+      .accounts({ project, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  const accept = (project: PublicKey, authority: Keypair) =>
+    program.methods
+      .acceptProjectTransfer()
+      .accounts({ project, authority: authority.publicKey } as any)
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+    buyer = Keypair.generate();
+  });
+
+  it("Transfers a project once the new owner accepts", async () => {
+    const project = await registerProject(owner, uniqueId("XFER"));
+
+    await propose(project, owner, buyer.publicKey);
+    let projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.pendingOwner.toString(), buyer.publicKey.toString());
+
+    await expectError(accept(project, Keypair.generate()), "NotPendingProjectOwner");
+
+    await accept(project, buyer);
+    projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.owner.toString(), buyer.publicKey.toString());
+    assert.isNull(projectAccount.pendingOwner);
+    // Same account: the PDA is seeded by the creator, not the owner
+    assert.equal(projectAccount.creator.toString(), owner.publicKey.toString());
+
+    // The previous owner can no longer hand it on
+    await expectError(propose(project, owner, owner.publicKey), "NotProjectOwner");
+
+    console.log("✅ Project ownership transferred successfully");
+  });
+
+  it("Cannot accept a cancelled transfer", async () => {
+    const project = await registerProject(owner, uniqueId("XFER"));
+
+    await propose(project, owner, buyer.publicKey);
+    await program.methods
+      .cancelProjectTransfer()
+      .accounts({ project, authority: owner.publicKey } as any)
+      .signers([owner])
+      .rpc();
+
+    await expectError(accept(project, buyer), "NoPendingProjectTransfer");
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.owner.toString(), owner.publicKey.toString());
+  });
+
+  it("Only the owner can propose a transfer", async () => {
+    const project = await registerProject(owner, uniqueId("XFER"));
+    await expectError(propose(project, buyer, buyer.publicKey), "NotProjectOwner");
+  });
+});
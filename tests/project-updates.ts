@@ -0,0 +1,112 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  registryPda,
+  configPda,
+  amendmentPda,
+  otherCid,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitForReview,
+  verifyProject,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("project updates", () => {
+  let owner: Keypair;
+
+  // Fields left out of `changes` encode as None, i.e. unchanged
+  const update = async (project: PublicKey, changes: object, editor = owner) => {
+    const { amendmentCount } = await program.account.project.fetch(project);
+    const amendment = amendmentPda(project, amendmentCount);
+    await program.methods
+      .updateProject(changes as any)
+      .accounts({
+        config: configPda,
+        project,
+        amendment,
+        programme: null,
+        registry: registryPda,
+        owner: editor.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([editor])
+      .rpc();
+    return amendment;
+  };
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+  });
+
+  it("Edits a pending project and records the amendment", async () => {
+    const project = await registerProject(owner, uniqueId("EDIT"));
+
+    const amendment = await update(project, { carbonTonsEstimated: new anchor.BN(1200) });
+
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.carbonTonsEstimated.toString(), "1200");
+    assert.equal(projectAccount.amendmentCount, 1);
+    assert.deepEqual(projectAccount.verificationStatus, { pending: {} });
+
+    const record = await program.account.projectAmendment.fetch(amendment);
+    assert.equal(record.index, 0);
+    assert.equal(record.editor.toString(), owner.publicKey.toString());
+    assert.isTrue(record.carbonScienceChanged);
+    assert.deepEqual(record.previousStatus, { pending: {} });
+    assert.equal(record.update.carbonTonsEstimated.toString(), "1200");
+
+    console.log("✅ Pending project amended successfully");
+  });
+
+  it("Rejects empty updates and edits by anyone but the owner", async () => {
+    const project = await registerProject(owner, uniqueId("EDIT"));
+
+    await expectError(update(project, {}), "EmptyProjectUpdate");
+    await expectError(
+      update(project, { ipfsCid: otherCid }, await fundedKeypair()),
+      "ConstraintHasOne"
+    );
+  });
+
+  it("Keeps the carbon science fixed while a project is under review", async () => {
+    const project = await registerProject(owner, uniqueId("EDIT"));
+    await submitForReview(owner, project);
+
+    await expectError(
+      update(project, { carbonTonsEstimated: new anchor.BN(5000) }),
+      "ProjectNotEditable"
+    );
+
+    // Supporting material can still change
+    await update(project, { ipfsCid: otherCid });
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.ipfsCid, otherCid);
+    assert.equal(projectAccount.carbonTonsEstimated.toString(), "1000");
+    assert.deepEqual(projectAccount.verificationStatus, { underReview: {} });
+  });
+
+  it("Sends a verified project back to review when its carbon science changes", async () => {
+    const project = await registerProject(owner, uniqueId("EDIT"));
+    await submitForReview(owner, project);
+    await verifyProject(project);
+
+    // A price change leaves the verification alone
+    await update(project, { pricePerTon: new anchor.BN(25) });
+    let projectAccount = await program.account.project.fetch(project);
+    assert.deepEqual(projectAccount.verificationStatus, { verified: {} });
+
+    const amendment = await update(project, { carbonTonsEstimated: new anchor.BN(900) });
+    projectAccount = await program.account.project.fetch(project);
+    assert.deepEqual(projectAccount.verificationStatus, { underReview: {} });
+
+    const record = await program.account.projectAmendment.fetch(amendment);
+    assert.deepEqual(record.previousStatus, { verified: {} });
+    assert.deepEqual(record.newStatus, { underReview: {} });
+  });
+});
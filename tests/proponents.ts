@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  TOKEN_DECIMALS,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitForReview,
+  verifyProject,
+  mintCredits,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("co-proponents", () => {
+  let owner: Keypair;
+  let partner: Keypair;
+  let project: PublicKey;
+
+  const proposalPda = (index: number) => {
+    const seed = Buffer.alloc(4);
+    seed.writeUInt32LE(index);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("proponent_proposal"), project.toBuffer(), seed],
+      program.programId
+    )[0];
+  };
+
+  const propose = async (proposer: Keypair, action: object) => {
+    const { proponentProposalCount } = await program.account.project.fetch(project);
+    const proposal = proposalPda(proponentProposalCount);
+    await program.methods
+      .createProponentProposal(action as any)
+      .accounts({
+        proposal,
+        project,
+        proposer: proposer.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([proposer])
+      .rpc();
+    return proposal;
+  };
+
+  const approve = (proposal: PublicKey, approver: Keypair) =>
+    program.methods
+      .approveProponentProposal()
+      .accounts({ proposal, project, approver: approver.publicKey } as any)
+      .signers([approver])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+    partner = await fundedKeypair();
+    project = await registerProject(owner, uniqueId("PROP"));
+    await submitForReview(owner, project);
+    await verifyProject(project);
+  });
+
+  it("Sets co-proponents with shares and an approval threshold", async () => {
+    const setProponents = (partnerShare: number, threshold: number) =>
+      program.methods
+        .setProjectProponents(
+          [
+            { key: owner.publicKey, role: { developer: {} }, shareBps: 10_000 - partnerShare },
+            { key: partner.publicKey, role: { community: {} }, shareBps: partnerShare },
+          ] as any,
+          threshold
+        )
+        .accounts({
+          project,
+          proposal: null,
+          authority: owner.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([owner])
+        .rpc();
+
+    // More approvals than proponents could never be reached
+    await expectError(setProponents(5_000, 3), "InvalidProponents");
+
+    await setProponents(5_000, 2);
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.proponents.length, 2);
+    assert.equal(projectAccount.proponents[1].key.toString(), partner.publicKey.toString());
+    assert.equal(projectAccount.proponents[1].shareBps, 5_000);
+    assert.equal(projectAccount.proponentThreshold, 2);
+
+    // From now on the list itself changes only by proposal
+    await expectError(setProponents(4_000, 2), "ProponentApprovalRequired");
+  });
+
+  it("Mints only once enough proponents approve", async () => {
+    // Owner-only minting is over
+    await expectError(mintCredits(owner, project, 1), "ProponentApprovalRequired");
+
+    const proposal = await propose(owner, {
+      mintCredits: {
+        amount: new anchor.BN(10 ** TOKEN_DECIMALS),
+        vintageYear: new Date().getUTCFullYear(),
+        recipient: owner.publicKey,
+      },
+    });
+    await expectError(mintCredits(owner, project, 1, proposal), "InsufficientApprovals");
+
+    await expectError(approve(proposal, owner), "AlreadyApproved");
+    await expectError(approve(proposal, Keypair.generate()), "NotProponent");
+    await approve(proposal, partner);
+
+    // The approvals cover exactly the proposed action
+    await expectError(mintCredits(owner, project, 2, proposal), "ProposalActionMismatch");
+    await mintCredits(owner, project, 1, proposal);
+
+    const projectAccount = await program.account.project.fetch(project);
+    assert.equal(projectAccount.creditsIssued.toString(), (10 ** TOKEN_DECIMALS).toString());
+    const executed = await program.account.proponentProposal.fetch(proposal);
+    assert.isTrue(executed.executed);
+
+    await expectError(mintCredits(owner, project, 1, proposal), "ProposalAlreadyExecuted");
+
+    console.log("✅ Co-proponent mint approved and executed successfully");
+  });
+
+  it("Only proponents can propose", async () => {
+    await expectError(
+      propose(await fundedKeypair(), {
+        mintCredits: {
+          amount: new anchor.BN(1),
+          vintageYear: new Date().getUTCFullYear(),
+          recipient: owner.publicKey,
+        },
+      }),
+      "NotProponent"
+    );
+  });
+});
@@ -0,0 +1,104 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, getAccount } from "@solana/spl-token";
+import { assert } from "chai";
+import {
+  program,
+  provider,
+  admin,
+  TOKEN_DECIMALS,
+  registryPda,
+  configPda,
+  tokenMint,
+  bufferPoolPda,
+  ipfsCid,
+  hectares,
+  tonnes,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitForReview,
+  verifyProject,
+  mintCredits,
+  uniqueId,
+  expectError,
+} from "./helpers";
+
+describe("reversals", () => {
+  let owner: Keypair;
+
+  const issuingProject = async (tokens: number) => {
+    const project = await registerProject(owner, uniqueId("REV"));
+    await submitForReview(owner, project);
+    await verifyProject(project);
+    await mintCredits(owner, project, tokens);
+    return project;
+  };
+
+  const reportReversal = async (project: PublicKey, tonsLost: number, auditor = admin) => {
+    const { reversalCount } = await program.account.project.fetch(project);
+    const seed = Buffer.alloc(4);
+    seed.writeUInt32LE(reversalCount);
+    const reversal = PublicKey.findProgramAddressSync(
+      [Buffer.from("reversal"), project.toBuffer(), seed],
+      program.programId
+    )[0];
+    await program.methods
+      .reportReversal(ipfsCid, hectares(1), tonnes(tonsLost), false)
+      .accounts({
+        config: configPda,
+        project,
+        reversal,
+        registry: registryPda,
+        carbonTokenMint: tokenMint,
+        bufferPool: bufferPoolPda,
+        auditorRole: null,
+        auditor: auditor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .signers([auditor])
+      .rpc();
+    return reversal;
+  };
+
+  const whole = (tokens: number) => new anchor.BN(tokens).mul(new anchor.BN(10 ** TOKEN_DECIMALS));
+
+  before(async () => {
+    await ensureRegistry();
+    owner = await fundedKeypair();
+  });
+
+  it("Cancels only the project's own share of the buffer pool", async () => {
+    // The default 10% buffer: one token from A, ten from B
+    const projectA = await issuingProject(10);
+    const projectB = await issuingProject(100);
+    const poolBefore = (await getAccount(provider.connection, bufferPoolPda)).amount;
+
+    await expectError(reportReversal(projectA, 5, await fundedKeypair()), "MissingRole");
+    const reversal = await reportReversal(projectA, 5);
+
+    const record = await program.account.reversal.fetch(reversal);
+    assert.equal(record.tokensLost.toString(), whole(5).toString());
+    assert.equal(record.bufferCancelled.toString(), whole(1).toString());
+    assert.equal(record.liability.toString(), whole(4).toString());
+
+    const a = await program.account.project.fetch(projectA);
+    assert.equal(a.bufferBalance.toString(), "0");
+    assert.equal(a.reversalLiability.toString(), whole(4).toString());
+    assert.deepEqual(a.verificationStatus, { suspended: {} });
+
+    // B's contribution is untouched, and the pool lost only A's share
+    const b = await program.account.project.fetch(projectB);
+    assert.equal(b.bufferBalance.toString(), whole(10).toString());
+    const poolAfter = (await getAccount(provider.connection, bufferPoolPda)).amount;
+    assert.equal((poolBefore - poolAfter).toString(), whole(1).toString());
+
+    console.log("✅ Reversal charged to the project's own buffer successfully");
+  });
+
+  it("Rejects reversals against projects without credits", async () => {
+    const project = await registerProject(owner, uniqueId("REV"));
+    await expectError(reportReversal(project, 5), "NoCreditsIssued");
+  });
+});
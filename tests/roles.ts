@@ -0,0 +1,119 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import {
+  program,
+  admin,
+  registryPda,
+  ROLE,
+  rolePda,
+  ensureRegistry,
+  fundedKeypair,
+  registerProject,
+  submitForReview,
+  verifyProject,
+  uniqueId,
+  grantRole,
+  revokeRole,
+  expectError,
+} from "./helpers";
+
+describe("roles", () => {
+  const trade = (authority: Keypair, authorityRole: anchor.web3.PublicKey | null) =>
+    program.methods
+      .tradeCredits(new anchor.BN(1))
+      .accounts({
+        registry: registryPda,
+        authorityRole,
+        authority: authority.publicKey,
+      } as any)
+      .signers([authority])
+      .rpc();
+
+  before(async () => {
+    await ensureRegistry();
+  });
+
+  it("Grants a role that lets its holder act, and revokes it again", async () => {
+    const operator = Keypair.generate();
+    const assignment = await grantRole(operator.publicKey, ROLE.marketplaceOperator);
+
+    const role = await program.account.roleAssignment.fetch(assignment);
+    assert.equal(role.holder.toString(), operator.publicKey.toString());
+    assert.deepEqual(role.role, { marketplaceOperator: {} });
+    assert.equal(role.grantedBy.toString(), admin.publicKey.toString());
+
+    await trade(operator, assignment);
+
+    await revokeRole(assignment);
+    assert.isNull(await program.account.roleAssignment.fetchNullable(assignment));
+    await expectError(trade(operator, null), "MissingRole");
+
+    console.log("✅ Role granted and revoked successfully");
+  });
+
+  it("Rejects a role assignment held by someone else", async () => {
+    const operator = Keypair.generate();
+    const impostor = Keypair.generate();
+    const assignment = await grantRole(operator.publicKey, ROLE.marketplaceOperator);
+
+    await expectError(trade(impostor, assignment), "MissingRole");
+    // Nor does a different role stand in for the one required
+    const auditor = await grantRole(impostor.publicKey, ROLE.auditor);
+    await expectError(trade(impostor, auditor), "MissingRole");
+
+    await revokeRole(assignment);
+    await revokeRole(auditor);
+  });
+
+  it("Lets a delegated admin manage roles other than admin", async () => {
+    const delegate = await fundedKeypair();
+    const delegateRole = await grantRole(delegate.publicKey, ROLE.admin);
+    const verifier = Keypair.generate();
+
+    const grant = (holder: Keypair, role: number, authorityRole: anchor.web3.PublicKey | null) =>
+      program.methods
+        .grantRole(holder.publicKey, { [Object.keys(ROLE)[role]]: {} } as any)
+        .accounts({
+          roleAssignment: rolePda(holder.publicKey, role),
+          registry: registryPda,
+          authorityRole,
+          authority: delegate.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .signers([delegate])
+        .rpc();
+
+    await grant(verifier, ROLE.verifier, delegateRole);
+    const granted = await program.account.roleAssignment.fetch(rolePda(verifier.publicKey, ROLE.verifier));
+    assert.equal(granted.grantedBy.toString(), delegate.publicKey.toString());
+
+    // Admin rights come from the registry admin only
+    await expectError(grant(verifier, ROLE.admin, delegateRole), "Unauthorized");
+    // And without its own assignment the delegate is nobody
+    await expectError(grant(Keypair.generate(), ROLE.verifier, null), "MissingRole");
+
+    await revokeRole(rolePda(verifier.publicKey, ROLE.verifier));
+    await revokeRole(delegateRole);
+  });
+
+  it("Verifies projects with the verifier role rather than admin rights", async () => {
+    const owner = await fundedKeypair();
+    const project = await registerProject(owner, uniqueId("ROLE"));
+    await submitForReview(owner, project);
+
+    // An admin delegate is not a verifier
+    const delegate = await fundedKeypair();
+    const delegateRole = await grantRole(delegate.publicKey, ROLE.admin);
+    await expectError(verifyProject(project, 800, delegate, delegateRole), "MissingRole");
+
+    const verifier = await fundedKeypair();
+    const verifierRole = await grantRole(verifier.publicKey, ROLE.verifier);
+    await verifyProject(project, 800, verifier, verifierRole);
+    const projectAccount = await program.account.project.fetch(project);
+    assert.deepEqual(projectAccount.verificationStatus, { verified: {} });
+
+    await revokeRole(delegateRole);
+    await revokeRole(verifierRole);
+  });
+});
@@ -474,7 +474,8 @@ export async function mintVerifiedCredits(
   wallet: any,
   projectId: string,
   amount: number,
-  recipient: PublicKey
+  recipient: PublicKey,
+  vintageYear: number = new Date().getUTCFullYear()
 ) {
   try {
     const { connection, program } = initializeSolana(wallet);
@@ -491,7 +492,7 @@ export async function mintVerifiedCredits(
     );
 
     const tx = await program.methods
-      .mintVerifiedCredits(new BN(amount * Math.pow(10, CARBON_TOKEN_DECIMALS))) // Convert to token units
+      .mintVerifiedCredits(new BN(amount * Math.pow(10, CARBON_TOKEN_DECIMALS)), vintageYear) // Convert to token units
       .accounts({
         project: projectPDA,
         registry: registryPDA,